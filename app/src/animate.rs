@@ -0,0 +1,162 @@
+use crate::font;
+use anyhow::{bail, Result};
+use solutions::grid::{Grid, Style};
+use solutions::runner::Runner;
+use solutions::visualize::{Frame, Visualize};
+use solutions::Parts;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+// Background first, then one color per style, matching the web canvas.
+const PALETTE: [u8; 15] = [
+    0x24, 0x28, 0x3b, // background
+    0xa9, 0xb1, 0xd6, // plain
+    0x41, 0x48, 0x68, // dim
+    0x00, 0xcc, 0x00, // highlight
+    0xff, 0xff, 0x66, // accent
+];
+
+fn color_index(style: Style) -> u8 {
+    match style {
+        Style::Plain => 1,
+        Style::Dim => 2,
+        Style::Highlight => 3,
+        Style::Accent => 4,
+    }
+}
+
+fn pixels(grid: &Grid, width: usize, height: usize, scale: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let Some(cell) = grid.cell(x, y) else {
+                continue;
+            };
+
+            if cell.glyph == ' ' {
+                continue;
+            }
+
+            let index = color_index(cell.style);
+
+            for row in y * scale..(y + 1) * scale {
+                pixels[row * width + x * scale..row * width + (x + 1) * scale].fill(index);
+            }
+        }
+    }
+
+    pixels
+}
+
+// Font pixels per `scale`, so that text at the default scale stays legible without
+// making the image much larger than a grid would.
+fn text_zoom(scale: usize) -> usize {
+    (scale / 2).max(1)
+}
+
+fn frame_size(frame: &Frame, scale: usize) -> (usize, usize) {
+    match frame {
+        Frame::Grid(grid) => (grid.width * scale, grid.height * scale),
+        Frame::Text(text) => font::size(text, text_zoom(scale)),
+    }
+}
+
+fn frame_pixels(frame: &Frame, width: usize, height: usize, scale: usize) -> Vec<u8> {
+    match frame {
+        Frame::Grid(grid) => pixels(grid, width, height, scale),
+        Frame::Text(text) => {
+            let mut pixels = vec![0; width * height];
+            font::draw(
+                text,
+                &mut pixels,
+                width,
+                text_zoom(scale),
+                color_index(Style::Plain),
+            );
+
+            pixels
+        }
+    }
+}
+
+pub fn animate(
+    runner: &Runner,
+    day: usize,
+    input: &str,
+    parts: Parts,
+    output: &Path,
+    scale: u16,
+    delay: u16,
+) -> Result<()> {
+    let mut frames = None;
+
+    let report = runner.run_with(day, input, parts, |solver| {
+        frames = solver.visualize().map(Visualize::frames);
+    })?;
+
+    if let Some(failure) = report.failure() {
+        bail!(
+            "day {} {} {} error={}",
+            day,
+            failure.phase.name(),
+            failure.outcome.status(),
+            failure.error().unwrap_or_default()
+        );
+    }
+
+    let Some(frames) = frames.transpose()? else {
+        bail!("day {} has no visualization", day);
+    };
+
+    if frames.is_empty() {
+        bail!("day {} produced no frames", day);
+    }
+
+    let scale = scale.max(1) as usize;
+    let sizes: Vec<(usize, usize)> = frames
+        .iter()
+        .map(|frame| frame_size(frame, scale))
+        .collect();
+    let columns = sizes.iter().map(|size| size.0).max().unwrap_or(0).max(1);
+    let rows = sizes.iter().map(|size| size.1).max().unwrap_or(0).max(1);
+
+    let (Ok(width), Ok(height)) = (u16::try_from(columns), u16::try_from(rows)) else {
+        bail!(
+            "{}x{} pixels at scale {} is too large for a gif",
+            columns,
+            rows,
+            scale
+        );
+    };
+
+    let mut encoder = gif::Encoder::new(
+        BufWriter::new(File::create(output)?),
+        width,
+        height,
+        &PALETTE,
+    )?;
+
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for frame in &frames {
+        let pixels = frame_pixels(frame, width as usize, height as usize, scale);
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+
+        // GIF delays are in hundredths of a second, and viewers replace 0 with their own.
+        frame.delay = (delay / 10).max(1);
+        encoder.write_frame(&frame)?;
+    }
+
+    println!(
+        "day={} frames={} size={}x{} wrote path={}",
+        day,
+        frames.len(),
+        width,
+        height,
+        output.display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,322 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+pub const YEAR: u16 = 2023;
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+
+const USER_AGENT: &str = concat!(
+    "github.com/kallberg/aoc2023 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("no session token, set {SESSION_ENV} or create session file {0}")]
+    NoSession(PathBuf),
+    #[error("read session file {0}: {1}")]
+    SessionFile(PathBuf, io::Error),
+    #[error("day {0} is not unlocked yet")]
+    Locked(usize),
+    #[error("request {0} failed with status {1}")]
+    Status(String, u16),
+    #[error("request {0} failed: {1}")]
+    Transport(String, Box<ureq::Transport>),
+    #[error("read response {0}: {1}")]
+    Body(String, io::Error),
+    #[error("unrecognized response from {0}")]
+    UnknownResponse(String),
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+fn session_file() -> PathBuf {
+    match env::var_os(SESSION_FILE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join(".session"),
+    }
+}
+
+fn load_session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = session_file();
+
+    match fs::read_to_string(&path) {
+        Ok(session) => Ok(session.trim().to_string()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(ClientError::NoSession(path)),
+        Err(err) => Err(ClientError::SessionFile(path, err)),
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str) -> Result<Self, ClientError> {
+        Ok(Client::with_session(base_url, load_session()?))
+    }
+
+    pub fn with_session(base_url: &str, session: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn url(&self, day: usize, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn read_body(url: &str, response: ureq::Response) -> Result<String, ClientError> {
+        let mut body = String::new();
+
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .map_err(|err| ClientError::Body(url.to_string(), err))?;
+
+        Ok(body)
+    }
+
+    fn send(
+        url: &str,
+        day: usize,
+        result: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        match result {
+            Ok(response) => Client::read_body(url, response),
+            Err(ureq::Error::Status(404, _)) => Err(ClientError::Locked(day)),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(ClientError::Status(url.to_string(), status))
+            }
+            Err(ureq::Error::Transport(transport)) => {
+                Err(ClientError::Transport(url.to_string(), Box::new(transport)))
+            }
+        }
+    }
+
+    pub fn input(&self, day: usize) -> Result<String, ClientError> {
+        let url = self.url(day, "/input");
+        let result = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+
+        Client::send(&url, day, result)
+    }
+
+    pub fn submit(&self, day: usize, part: u8, answer: &str) -> Result<Outcome, ClientError> {
+        let url = self.url(day, "/answer");
+        let level = part.to_string();
+        let result = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)]);
+
+        let page = Client::send(&url, day, result)?;
+
+        Outcome::parse(&page).ok_or(ClientError::UnknownResponse(url))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    RateLimited(Duration),
+}
+
+impl Outcome {
+    fn article(page: &str) -> &str {
+        let Some((_, article)) = page.split_once("<article>") else {
+            return page;
+        };
+
+        article
+            .split_once("</article>")
+            .map(|(article, _)| article)
+            .unwrap_or(article)
+    }
+
+    fn wait(article: &str) -> Duration {
+        let Some((_, wait)) = article.split_once("You have ") else {
+            return Duration::ZERO;
+        };
+
+        let wait = wait.split_once(" left").map(|(wait, _)| wait).unwrap_or("");
+        let mut seconds = 0;
+
+        for token in wait.split_whitespace() {
+            let (value, unit) = token.split_at(token.len().saturating_sub(1));
+            let Ok(value) = value.parse::<u64>() else {
+                continue;
+            };
+
+            seconds += match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => 0,
+            };
+        }
+
+        Duration::from_secs(seconds)
+    }
+
+    pub fn parse(page: &str) -> Option<Outcome> {
+        let article = Outcome::article(page);
+
+        if article.contains("That's the right answer") {
+            return Some(Outcome::Correct);
+        }
+
+        if article.contains("That's not the right answer") {
+            if article.contains("too high") {
+                return Some(Outcome::TooHigh);
+            }
+
+            if article.contains("too low") {
+                return Some(Outcome::TooLow);
+            }
+
+            return Some(Outcome::Wrong);
+        }
+
+        if article.contains("Did you already complete it") {
+            return Some(Outcome::AlreadySolved);
+        }
+
+        if article.contains("You gave an answer too recently") {
+            return Some(Outcome::RateLimited(Outcome::wait(article)));
+        }
+
+        None
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::RateLimited(_) => "rate_limited",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too_high" => Some(Outcome::TooHigh),
+            "too_low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "already_solved" => Some(Outcome::AlreadySolved),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en-us\">\n<body>\n<main>\n<article>{}</article>\n</main>\n</body>\n</html>\n",
+            article
+        )
+    }
+
+    const CORRECT: &str = "<p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. <a href=\"/2023/day/1#part2\">[Continue to Part Two]</a></p>";
+    const TOO_HIGH: &str = "<p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p>";
+    const TOO_LOW: &str = "<p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p>";
+    const WRONG: &str = "<p>That's not the right answer.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p>";
+    const ALREADY_SOLVED: &str = "<p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a></p>";
+    const RATE_LIMITED: &str = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 35s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a></p>";
+
+    #[test]
+    fn parses_outcomes() {
+        let cases = [
+            (CORRECT, Outcome::Correct),
+            (TOO_HIGH, Outcome::TooHigh),
+            (TOO_LOW, Outcome::TooLow),
+            (WRONG, Outcome::Wrong),
+            (ALREADY_SOLVED, Outcome::AlreadySolved),
+            (RATE_LIMITED, Outcome::RateLimited(Duration::from_secs(275))),
+        ];
+
+        for (article, outcome) in cases {
+            assert_eq!(Outcome::parse(&page(article)), Some(outcome));
+        }
+
+        assert_eq!(Outcome::parse(&page("<p>Something else.</p>")), None);
+    }
+
+    #[test]
+    fn ignores_text_outside_the_article() {
+        let page = format!("<title>too high</title>{}", page(TOO_LOW));
+
+        assert_eq!(Outcome::parse(&page), Some(Outcome::TooLow));
+    }
+
+    #[test]
+    fn parses_waits() {
+        let cases = [
+            ("You have 4m 35s left to wait.", 275),
+            ("You have 35s left to wait.", 35),
+            ("You have 1h 2m 3s left to wait.", 3723),
+            ("No wait here.", 0),
+        ];
+
+        for (article, seconds) in cases {
+            assert_eq!(Outcome::wait(article), Duration::from_secs(seconds));
+        }
+    }
+
+    #[test]
+    fn submits_form_and_parses_response() {
+        let stub = Stub::start(|request| match request.lines().next().unwrap_or_default() {
+            line if line.starts_with("POST /2023/day/7/answer ") => (200, page(TOO_HIGH)),
+            _ => (404, "Not Found".to_string()),
+        });
+
+        let client = Client::with_session(&stub.url, "0123abcd".to_string());
+
+        assert_eq!(client.submit(7, 2, "6440").unwrap(), Outcome::TooHigh);
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+
+        let request = requests[0].to_ascii_lowercase();
+        assert!(request.contains("cookie: session=0123abcd"));
+        assert!(request.contains("content-type: application/x-www-form-urlencoded"));
+        assert!(request.ends_with("level=2&answer=6440"));
+
+        assert!(matches!(
+            client.submit(26, 1, "0"),
+            Err(ClientError::Locked(26))
+        ));
+    }
+}
@@ -0,0 +1,9 @@
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    web::serve()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    eprintln!("the solver worker only runs in the browser, see app/index.html");
+}
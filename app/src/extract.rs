@@ -0,0 +1,325 @@
+use anyhow::{bail, Result};
+use solutions::provider::{Directory, Provider};
+use solutions::{Example, Parts};
+use std::borrow::Cow;
+use std::fs;
+use std::path::Path;
+
+struct Article {
+    blocks: Vec<String>,
+    answer: Option<String>,
+}
+
+fn decode(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn sections<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut sections = vec![];
+    let mut offset = 0;
+
+    while let Some(start) = html[offset..].find(open) {
+        let start = offset + start + open.len();
+
+        let Some(end) = html[start..].find(close) else {
+            break;
+        };
+
+        sections.push((start, &html[start..start + end]));
+        offset = start + end + close.len();
+    }
+
+    sections
+}
+
+fn articles(page: &str) -> Vec<Article> {
+    let mut articles = vec![];
+
+    for (_, article) in sections(page, "<article", "</article>") {
+        let blocks = sections(article, "<pre><code>", "</code></pre>")
+            .into_iter()
+            .map(|(_, block)| decode(block))
+            .collect();
+
+        let mut answers = sections(article, "<code><em>", "</em></code>");
+        answers.extend(sections(article, "<em><code>", "</code></em>"));
+        answers.sort_by_key(|(position, _)| *position);
+
+        let answer = answers.last().map(|(_, answer)| decode(answer));
+
+        articles.push(Article { blocks, answer });
+    }
+
+    articles
+}
+
+fn candidates(articles: &[Article]) {
+    for (index, article) in articles.iter().enumerate() {
+        let part = index + 1;
+
+        for (block_index, block) in article.blocks.iter().enumerate() {
+            println!(
+                "part={} block={} lines={} first={:?}",
+                part,
+                block_index,
+                block.lines().count(),
+                block.lines().next().unwrap_or("")
+            );
+        }
+
+        if let Some(answer) = &article.answer {
+            println!("part={} expected={}", part, answer);
+        }
+    }
+}
+
+fn examples(articles: &[Article], name: &str) -> Result<Vec<Example>> {
+    let Some(part_1) = articles.first() else {
+        bail!("no puzzle articles in page");
+    };
+
+    let Some(input_1) = part_1.blocks.first() else {
+        bail!("no code blocks in part 1");
+    };
+
+    let example = |name: String, parts, input: &str, expected_1, expected_2| Example {
+        name: Cow::Owned(name),
+        parts,
+        input: Cow::Owned(input.to_string()),
+        expected_1,
+        expected_2,
+    };
+
+    let expected_1 = part_1.answer.clone().map(Cow::Owned);
+
+    let Some(part_2) = articles.get(1) else {
+        return Ok(vec![example(
+            name.to_string(),
+            Parts::One,
+            input_1,
+            expected_1,
+            None,
+        )]);
+    };
+
+    let expected_2 = part_2.answer.clone().map(Cow::Owned);
+
+    match part_2.blocks.first() {
+        Some(input_2) if input_2 != input_1 => Ok(vec![
+            example(format!("{}_1", name), Parts::One, input_1, expected_1, None),
+            example(format!("{}_2", name), Parts::Two, input_2, None, expected_2),
+        ]),
+        _ => Ok(vec![example(
+            name.to_string(),
+            Parts::Both,
+            input_1,
+            expected_1,
+            expected_2,
+        )]),
+    }
+}
+
+pub fn extract(
+    directory: &Directory,
+    day: usize,
+    page: &Path,
+    name: &str,
+    dry_run: bool,
+    force: bool,
+) -> Result<()> {
+    let articles = articles(&fs::read_to_string(page)?);
+
+    candidates(&articles);
+
+    let examples = examples(&articles, name)?;
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let existing = directory.examples(day)?;
+
+    for example in &examples {
+        let taken = existing
+            .iter()
+            .any(|existing| existing.name == example.name);
+
+        if taken && !force {
+            bail!("day {} already has example {}", day, example.name);
+        }
+    }
+
+    for example in &examples {
+        let path = directory.write_example(day, example)?;
+
+        println!(
+            "day={} example={} parts={:?} path={}",
+            day,
+            example.name,
+            example.parts,
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trimmed from a saved day 1 page after solving both parts.
+    const SAME_EXAMPLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>56108</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>Adding these together produces <em><code>142</code></em>, and the <code>&lt;</code> and <code>&amp;</code> signs stay text.</p>
+</article>
+</main>"#;
+
+    // Trimmed from a saved day 1 page where part two brings its own example.
+    const SPLIT_EXAMPLES: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<pre><code>1abc2
+treb7uchet
+</code></pre>
+<p>Values <code><em>12</em></code> and <code><em>77</em></code> add up to <code><em>89</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+&lt;x&gt; eightwothree
+</code></pre>
+<p>Adding these together produces <code><em>112</em></code>.</p>
+</article>
+</main>"#;
+
+    fn summary(example: &Example) -> (&str, Parts, &str, Option<&str>, Option<&str>) {
+        (
+            &example.name,
+            example.parts,
+            &example.input,
+            example.expected(1),
+            example.expected(2),
+        )
+    }
+
+    #[test]
+    fn decodes_tags_and_entities() {
+        assert_eq!(
+            decode("<em>a &lt;b&gt; &amp;lt; &quot;c&quot; &#39;d&apos;</em>"),
+            "a <b> &lt; \"c\" 'd'"
+        );
+    }
+
+    #[test]
+    fn takes_the_last_emphasized_code_as_the_answer() {
+        let articles = articles(SPLIT_EXAMPLES);
+
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].blocks, ["1abc2\ntreb7uchet\n"]);
+        assert_eq!(articles[0].answer.as_deref(), Some("89"));
+        assert_eq!(articles[1].blocks, ["two1nine\n<x> eightwothree\n"]);
+        assert_eq!(articles[1].answer.as_deref(), Some("112"));
+    }
+
+    #[test]
+    fn accepts_both_emphasis_orders() {
+        let articles = articles(SAME_EXAMPLE);
+
+        assert_eq!(articles[0].answer.as_deref(), Some("142"));
+        assert_eq!(articles[1].answer.as_deref(), Some("142"));
+    }
+
+    #[test]
+    fn shares_an_example_used_by_both_parts() {
+        let examples = examples(&articles(SAME_EXAMPLE), "example").unwrap();
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            summary(&examples[0]),
+            ("example", Parts::Both, input, Some("142"), Some("142"))
+        );
+    }
+
+    #[test]
+    fn splits_examples_that_differ_between_parts() {
+        let examples = examples(&articles(SPLIT_EXAMPLES), "example").unwrap();
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(
+            summary(&examples[0]),
+            (
+                "example_1",
+                Parts::One,
+                "1abc2\ntreb7uchet\n",
+                Some("89"),
+                None
+            )
+        );
+        assert_eq!(
+            summary(&examples[1]),
+            (
+                "example_2",
+                Parts::Two,
+                "two1nine\n<x> eightwothree\n",
+                None,
+                Some("112")
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_part_one_only_before_part_two_unlocks() {
+        let page = &SPLIT_EXAMPLES[..SPLIT_EXAMPLES
+            .find("<article class=\"day-desc\"><h2 id")
+            .unwrap()];
+        let examples = examples(&articles(page), "example").unwrap();
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            summary(&examples[0]),
+            (
+                "example",
+                Parts::One,
+                "1abc2\ntreb7uchet\n",
+                Some("89"),
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_pages_without_examples() {
+        assert!(examples(&articles("<main></main>"), "example").is_err());
+        assert!(examples(&articles("<article><p>No code.</p></article>"), "example").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::aoc::{Client, ClientError};
+use anyhow::Result;
+use solutions::provider::Directory;
+use solutions::vault::{self, Key, VaultError};
+use std::fs;
+use std::path::Path;
+
+fn cached(directory: &Directory, day: usize) -> bool {
+    let plaintext = fs::metadata(directory.input_path(day))
+        .map(|metadata| metadata.len() > 0)
+        .unwrap_or(false);
+
+    plaintext || directory.sealed_path(day).exists()
+}
+
+fn write(path: &Path, contents: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, contents)?;
+
+    Ok(())
+}
+
+pub fn fetch(directory: &Directory, base_url: &str, day: Option<usize>) -> Result<()> {
+    let key = match Key::load() {
+        Ok(key) => Some(key),
+        Err(VaultError::NoKey(_)) => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    download(directory, day, key.as_ref(), || Client::new(base_url))
+}
+
+// Inputs are sealed into the vault when a key is available, so a fetch never leaves
+// plaintext next to tracked files.
+fn download(
+    directory: &Directory,
+    day: Option<usize>,
+    key: Option<&Key>,
+    connect: impl FnOnce() -> Result<Client, ClientError>,
+) -> Result<()> {
+    let days: Vec<usize> = match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    let mut connect = Some(connect);
+    let mut client = None;
+
+    for index in days {
+        if cached(directory, index) {
+            println!("day={} cached", index);
+            continue;
+        }
+
+        let client = match (&client, connect.take()) {
+            (Some(client), _) => client,
+            (None, Some(connect)) => client.insert(connect()?),
+            (None, None) => unreachable!("client is connected once"),
+        };
+
+        let input = match client.input(index) {
+            Ok(input) => input,
+            Err(ClientError::Locked(_)) if day.is_none() => {
+                println!("day={} locked", index);
+                break;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        match key {
+            Some(key) => {
+                let path = directory.sealed_path(index);
+                write(&path, &vault::seal(key, &input))?;
+                println!("day={} fetched sealed path={}", index, path.display());
+            }
+            None => {
+                let path = directory.input_path(index);
+                write(&path, input.as_bytes())?;
+                println!("day={} fetched path={}", index, path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+    use std::env;
+    use std::process;
+
+    const SESSION: &str = "0123abcd";
+
+    fn directory(name: &str) -> (Directory, std::path::PathBuf) {
+        let root = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+        let _result = fs::remove_dir_all(&root);
+
+        (Directory::new(&root), root)
+    }
+
+    fn stub() -> Stub {
+        Stub::start(|request| match request.lines().next().unwrap_or_default() {
+            line if line.starts_with("GET /2023/day/1/input ") => (200, "1abc2\n".to_string()),
+            line if line.starts_with("GET /2023/day/2/input ") => (200, "Game 1\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        })
+    }
+
+    #[test]
+    fn sends_user_agent_and_session_cookie() {
+        let stub = stub();
+        let (directory, root) = directory("headers");
+
+        download(&directory, Some(1), None, || {
+            Ok(Client::with_session(&stub.url, SESSION.to_string()))
+        })
+        .unwrap();
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+
+        let request = requests[0].to_ascii_lowercase();
+        assert!(request.contains("user-agent: github.com/kallberg/aoc2023 app/"));
+        assert!(request.contains(&format!("cookie: session={}", SESSION)));
+        assert_eq!(
+            fs::read_to_string(directory.input_path(1)).unwrap(),
+            "1abc2\n"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn never_requests_cached_days() {
+        let stub = stub();
+        let (directory, root) = directory("cached");
+
+        write(&directory.input_path(1), b"cached\n").unwrap();
+        write(&directory.sealed_path(2), b"sealed").unwrap();
+
+        for day in [1, 2] {
+            download(&directory, Some(day), None, || {
+                Ok(Client::with_session(&stub.url, SESSION.to_string()))
+            })
+            .unwrap();
+        }
+
+        assert!(stub.requests().is_empty());
+        assert_eq!(
+            fs::read_to_string(directory.input_path(1)).unwrap(),
+            "cached\n"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn seals_inputs_when_a_key_is_available() {
+        let stub = stub();
+        let (directory, root) = directory("sealed");
+        let key = Key::generate();
+
+        download(&directory, Some(2), Some(&key), || {
+            Ok(Client::with_session(&stub.url, SESSION.to_string()))
+        })
+        .unwrap();
+
+        let sealed = fs::read(directory.sealed_path(2)).unwrap();
+        assert_eq!(vault::unseal(&key, &sealed).unwrap(), "Game 1\n");
+        assert!(!directory.input_path(2).exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn maps_not_found_to_locked() {
+        let stub = stub();
+        let (directory, root) = directory("locked");
+
+        let err = download(&directory, Some(3), None, || {
+            Ok(Client::with_session(&stub.url, SESSION.to_string()))
+        })
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ClientError>(),
+            Some(ClientError::Locked(3))
+        ));
+        assert!(!directory.input_path(3).exists());
+
+        download(&directory, None, None, || {
+            Ok(Client::with_session(&stub.url, SESSION.to_string()))
+        })
+        .unwrap();
+
+        assert_eq!(stub.requests().len(), 4);
+
+        let _result = fs::remove_dir_all(root);
+    }
+}
@@ -1,58 +1,383 @@
 #[cfg(not(target_arch = "wasm32"))]
+mod animate;
+#[cfg(not(target_arch = "wasm32"))]
+mod aoc;
+#[cfg(not(target_arch = "wasm32"))]
+mod extract;
+#[cfg(not(target_arch = "wasm32"))]
+mod fetch;
+#[cfg(not(target_arch = "wasm32"))]
+mod font;
+#[cfg(not(target_arch = "wasm32"))]
+mod scaffold;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod stub;
+#[cfg(not(target_arch = "wasm32"))]
+mod submit;
+#[cfg(not(target_arch = "wasm32"))]
+mod vault;
+#[cfg(not(target_arch = "wasm32"))]
+mod watch;
+
+#[cfg(not(target_arch = "wasm32"))]
+use solutions::provider::{Directory, Provider};
+#[cfg(not(target_arch = "wasm32"))]
 use solutions::*;
 
 #[cfg(not(target_arch = "wasm32"))]
-#[derive(Eq, PartialEq)]
-enum Parts {
-    One,
-    Two,
-    Both,
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Encrypt plaintext inputs into the vault
+    Seal {
+        #[arg(long)]
+        day: Option<usize>,
+        /// Delete the plaintext input after sealing
+        #[arg(long)]
+        remove: bool,
+    },
+    /// Decrypt vault inputs into plaintext input files
+    Unseal {
+        #[arg(long)]
+        day: Option<usize>,
+    },
+    /// Download puzzle inputs that are not already cached
+    Fetch {
+        #[arg(long)]
+        day: Option<usize>,
+        #[arg(long, env = "AOC_BASE_URL", default_value = aoc::BASE_URL)]
+        base_url: String,
+    },
+    /// Extract examples and expected answers from a saved puzzle page
+    Extract {
+        #[arg(long)]
+        day: usize,
+        page: std::path::PathBuf,
+        #[arg(long, default_value = "example")]
+        name: String,
+        /// Only list the candidate code blocks and answers
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite examples with the same name
+        #[arg(long)]
+        force: bool,
+    },
+    /// Generate a solver module for a day and wire it into the crates
+    NewDay { day: usize },
+    /// Browse days, inputs and answers in an interactive terminal UI
+    Tui,
+    /// Write the step by step visualization of the day as an animated gif
+    Animate {
+        output: std::path::PathBuf,
+        /// Pixels per grid cell
+        #[arg(long, default_value_t = 4)]
+        scale: u16,
+        /// Milliseconds per frame
+        #[arg(long, default_value_t = 100)]
+        delay: u16,
+    },
+    /// Solve one part and submit the answer
+    Submit {
+        #[arg(long)]
+        day: usize,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long, env = "AOC_BASE_URL", default_value = aoc::BASE_URL)]
+        base_url: String,
+    },
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(clap::Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(long, default_value_t = 11)]
+    day: usize,
+    #[arg(long, default_value = "both")]
+    part: Parts,
+    #[arg(long)]
+    example: Option<String>,
+    #[arg(long, env = "AOC_INPUTS")]
+    inputs: Option<std::path::PathBuf>,
+    #[cfg(feature = "embedded")]
+    #[arg(long, conflicts_with_all = ["inputs", "watch"])]
+    embedded: bool,
+    /// Re-solve whenever the input or example files of the day change
+    #[arg(long)]
+    watch: bool,
+    /// Solve every day that has an input, continuing past failures
+    #[arg(long, conflicts_with_all = ["example", "watch"])]
+    all: bool,
+    /// Stop a solver phase that runs longer than this, e.g. 500ms, 2s or 1m
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<std::time::Duration>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_timeout(value: &str) -> Result<std::time::Duration, String> {
+    use std::time::Duration;
+
+    let (number, unit) = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map(|index| value.split_at(index))
+        .unwrap_or((value, "s"));
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid timeout {}", value))?;
+
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        _ => {
+            return Err(format!(
+                "unknown timeout unit {}, expected ms, s or m",
+                unit
+            ))
+        }
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid timeout {}", value))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Args {
+    fn directory(&self) -> Directory {
+        match &self.inputs {
+            Some(path) => Directory::new(path),
+            None => Directory::default(),
+        }
+    }
+
+    fn provider(&self) -> Box<dyn Provider> {
+        #[cfg(feature = "embedded")]
+        if self.embedded {
+            return Box::new(solutions::provider::Embedded);
+        }
+
+        Box::new(self.directory())
+    }
+
+    fn runner(&self) -> runner::Runner {
+        runner::Runner::new(runner::SystemClock::default()).with_timeout(self.timeout)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn check(example: Option<&Example>, part: u8, solution: &str) -> String {
+    match example.and_then(|example| example.expected(part)) {
+        Some(expected) if expected == solution => format!(" expected={} pass", expected),
+        Some(expected) => format!(" expected={} fail", expected),
+        None => String::new(),
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn print_day(mut day: Box<dyn Solver>, index: usize, parts: Parts) {
-    use std::time::Instant;
+fn print_report(
+    report: &runner::RunReport,
+    example: Option<&Example>,
+    annotate: impl Fn(&runner::PhaseReport) -> String,
+) {
+    use runner::Outcome;
 
-    let time_start = Instant::now();
-    day.parse().unwrap();
-    let parse_time = time_start.elapsed();
-    println!("day={} parse - {:?}", index, parse_time);
+    for phase in &report.phases {
+        let label = match phase.phase.part() {
+            Some(part) => format!("part={}", part),
+            None => "parse".to_string(),
+        };
+
+        let (result, check) = match (&phase.outcome, phase.phase.part()) {
+            (Outcome::Done(Some(solution)), Some(part)) => (
+                format!(" solution={}", solution),
+                check(example, part, solution),
+            ),
+            (Outcome::Done(_), _) => (String::new(), String::new()),
+            (Outcome::Failed(error), _) => (format!(" error={}", error), String::new()),
+            (Outcome::Panicked(panic), _) => (
+                format!(
+                    " panicked message={:?} location={}",
+                    panic.message,
+                    panic.location.as_deref().unwrap_or("unknown")
+                ),
+                String::new(),
+            ),
+            (Outcome::TimedOut(timeout), _) => {
+                (format!(" timed out after={:?}", timeout), String::new())
+            }
+        };
 
-    if parts == Parts::Both || parts == Parts::One {
-        let time_start = Instant::now();
-        let part_1 = day.part_1().unwrap();
-        let part_1_time = time_start.elapsed();
         println!(
-            "day={} part={} solution={} - {:?}",
-            index, 1, part_1, part_1_time
+            "day={} {}{} - {:?}{}{}",
+            report.day,
+            label,
+            result,
+            phase.duration,
+            annotate(phase),
+            check
         );
     }
+}
 
-    if parts == Parts::Both || parts == Parts::Two {
-        let time_start = Instant::now();
-        let part_2 = day.part_2().unwrap();
-        let part_2_time = time_start.elapsed();
+#[cfg(not(target_arch = "wasm32"))]
+struct Source {
+    parts: Parts,
+    input: String,
+    example: Option<Example>,
+}
 
-        println!(
-            "day={} part={} solution={} - {:?}",
-            index, 2, part_2, part_2_time
+#[cfg(not(target_arch = "wasm32"))]
+fn load(args: &Args, provider: &dyn Provider) -> anyhow::Result<Source> {
+    use anyhow::{anyhow, bail};
+
+    let day = args.day;
+
+    let Some(name) = &args.example else {
+        return Ok(Source {
+            parts: args.part,
+            input: provider.input(day)?,
+            example: None,
+        });
+    };
+
+    let examples = provider.examples(day)?;
+
+    let Some(example) = examples
+        .iter()
+        .find(|example| example.name == name.as_str())
+    else {
+        let names: Vec<&str> = examples
+            .iter()
+            .map(|example| example.name.as_ref())
+            .collect();
+
+        bail!(
+            "day {} has no example {}, available: [{}]",
+            day,
+            name,
+            names.join(", ")
         );
+    };
+
+    let parts = args
+        .part
+        .intersect(example.parts)
+        .ok_or_else(|| anyhow!("example {} does not apply to part {:?}", name, args.part))?;
+
+    Ok(Source {
+        parts,
+        input: example.input.to_string(),
+        example: Some(example.clone()),
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn solve(args: &Args) -> anyhow::Result<()> {
+    use anyhow::bail;
+
+    let day = args.day;
+    let source = load(args, args.provider().as_ref())?;
+    let report = args.runner().run(day, &source.input, source.parts)?;
+
+    print_report(&report, source.example.as_ref(), |_| String::new());
+
+    if let Some(failure) = report.failure() {
+        bail!("day {} {} {}", day, failure.phase.name(), report.status());
     }
+
+    Ok(())
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn main() {
-    let day = 11;
-    let example = false;
-    let mut solver = solvers::Solvers::get(day).unwrap();
+fn solve_all(args: &Args) -> anyhow::Result<()> {
+    use anyhow::bail;
+    use solutions::provider::ProviderError;
+
+    let provider = args.provider();
+    let runner = args.runner();
+    let mut failed = vec![];
 
-    if example {
-        solver.setup(example::get(day));
-    } else {
-        solver.setup(input::get(day));
+    for day in 1..=25 {
+        let input = match provider.input(day) {
+            Ok(input) => input,
+            Err(err) => {
+                if !matches!(err.downcast_ref(), Some(ProviderError::MissingInput(_))) {
+                    println!("day={} error={}", day, err);
+                    failed.push(day);
+                }
+                continue;
+            }
+        };
+
+        let report = runner.run(day, &input, args.part)?;
+
+        print_report(&report, None, |_| String::new());
+
+        if report.failure().is_some() {
+            failed.push(day);
+        }
     }
 
-    print_day(solver, day, Parts::Both);
+    if !failed.is_empty() {
+        let days: Vec<String> = failed.iter().map(usize::to_string).collect();
+        bail!("days [{}] did not solve", days.join(", "));
+    }
+
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> anyhow::Result<()> {
+    use clap::Parser;
+
+    let args = Args::parse();
+
+    match &args.command {
+        Some(Command::Seal { day, remove }) => vault::seal(&args.directory(), *day, *remove),
+        Some(Command::Unseal { day }) => vault::unseal(&args.directory(), *day),
+        Some(Command::Fetch { day, base_url }) => fetch::fetch(&args.directory(), base_url, *day),
+        Some(Command::Extract {
+            day,
+            page,
+            name,
+            dry_run,
+            force,
+        }) => extract::extract(&args.directory(), *day, page, name, *dry_run, *force),
+        Some(Command::NewDay { day }) => scaffold::new_day(*day),
+        Some(Command::Submit {
+            day,
+            part,
+            base_url,
+        }) => submit::submit(
+            &args.runner(),
+            args.provider().as_ref(),
+            &args.directory(),
+            base_url,
+            *day,
+            *part,
+        ),
+        Some(Command::Tui) => tui::run(args.provider(), args.day, args.timeout),
+        Some(Command::Animate {
+            output,
+            scale,
+            delay,
+        }) => {
+            let source = load(&args, args.provider().as_ref())?;
+
+            animate::animate(
+                &args.runner(),
+                args.day,
+                &source.input,
+                source.parts,
+                output,
+                *scale,
+                *delay,
+            )
+        }
+        None if args.watch => watch::watch(&args),
+        None if args.all => solve_all(&args),
+        None => solve(&args),
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,242 @@
+use anyhow::{anyhow, bail, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+struct Edit {
+    path: PathBuf,
+    contents: String,
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| anyhow!("read {}: {}", path.display(), err))
+}
+
+fn wire_lib(lib: &str, module: &str) -> Result<String> {
+    let declaration = format!("mod {};", module);
+
+    if lib.lines().any(|line| line == declaration) {
+        bail!("{} is already declared in lib.rs", module);
+    }
+
+    let lines: Vec<&str> = lib.lines().collect();
+
+    let Some(first) = lines.iter().position(|line| line.starts_with("mod day_")) else {
+        bail!("no day modules found in lib.rs");
+    };
+
+    let count = lines[first..]
+        .iter()
+        .take_while(|line| line.starts_with("mod day_"))
+        .count();
+
+    let mut modules: Vec<&str> = lines[first..first + count].to_vec();
+    modules.push(&declaration);
+    modules.sort_by_key(|line| line.trim_end_matches(';'));
+
+    let mut wired: Vec<&str> = lines[..first].to_vec();
+    wired.extend(modules);
+    wired.extend(&lines[first + count..]);
+
+    Ok(wired.join("\n") + "\n")
+}
+
+fn wire_solvers(solvers: &str, day: usize, module: &str) -> Result<String> {
+    let placeholder = format!("{} => Some(Box::new(day_x::Day::default())),", day);
+    let implemented = format!("{} => Some(Box::new({}::Day::default())),", day, module);
+
+    // Whole lines only, so day 1 does not match the placeholders of days 11 and 21.
+    if !solvers.lines().any(|line| line.trim() == placeholder) {
+        bail!("day {} is not a placeholder in solvers.rs", day);
+    }
+
+    let wired = solvers
+        .lines()
+        .map(|line| {
+            if line.trim() == placeholder {
+                line.replace(&placeholder, &implemented)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n";
+
+    let Some(start) = wired.find("use crate::{") else {
+        bail!("no crate import found in solvers.rs");
+    };
+
+    let end = start
+        + wired[start..]
+            .find("};")
+            .ok_or_else(|| anyhow!("unterminated crate import in solvers.rs"))?;
+
+    let mut names: Vec<&str> = wired[start + "use crate::{".len()..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    names.push(module);
+    names.sort_by_key(|name| (name.starts_with(char::is_uppercase), *name));
+
+    let import = format!("use crate::{{\n    {},\n}}", names.join(", "));
+
+    Ok(format!(
+        "{}{}{}",
+        &wired[..start],
+        import,
+        &wired[end + 1..]
+    ))
+}
+
+pub fn new_day(day: usize) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {}", day);
+    }
+
+    let root = root();
+    let module = format!("day_{}", day);
+    let module_path = root.join("solutions/src").join(format!("{}.rs", module));
+
+    if module_path.exists() {
+        bail!("{} already exists", module_path.display());
+    }
+
+    let lib_path = root.join("solutions/src/lib.rs");
+    let solvers_path = root.join("solutions/src/solvers.rs");
+
+    let edits = [
+        Edit {
+            contents: TEMPLATE.replace("{{day}}", &day.to_string()),
+            path: module_path,
+        },
+        Edit {
+            contents: wire_lib(&read(&lib_path)?, &module)?,
+            path: lib_path,
+        },
+        Edit {
+            contents: wire_solvers(&read(&solvers_path)?, day, &module)?,
+            path: solvers_path,
+        },
+    ];
+
+    for edit in &edits {
+        fs::write(&edit.path, &edit.contents)?;
+        println!("day={} wrote path={}", day, edit.path.display());
+    }
+
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(edits.iter().map(|edit| &edit.path))
+        .status();
+
+    if !formatted.map(|status| status.success()).unwrap_or(false) {
+        println!("day={} rustfmt unavailable, sources left unformatted", day);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = include_str!("../../solutions/src/lib.rs");
+    const SOLVERS: &str = include_str!("../../solutions/src/solvers.rs");
+
+    fn added<'a>(before: &str, after: &'a str) -> Vec<&'a str> {
+        let before: Vec<&str> = before.lines().collect();
+
+        after
+            .lines()
+            .filter(|line| !before.contains(line))
+            .collect()
+    }
+
+    #[test]
+    fn declares_the_module_in_order() {
+        let wired = wire_lib(LIB, "day_12").unwrap();
+        let lines: Vec<&str> = wired.lines().collect();
+        let position = |line| lines.iter().position(|other| *other == line).unwrap();
+
+        assert_eq!(added(LIB, &wired), ["mod day_12;"]);
+        assert_eq!(lines.len(), LIB.lines().count() + 1);
+        assert_eq!(position("mod day_12;"), position("mod day_11;") + 1);
+        assert_eq!(position("mod day_2;"), position("mod day_12;") + 1);
+    }
+
+    #[test]
+    fn refuses_a_declared_module() {
+        assert!(wire_lib(LIB, "day_1").is_err());
+
+        let wired = wire_lib(LIB, "day_12").unwrap();
+        assert!(wire_lib(&wired, "day_12").is_err());
+    }
+
+    #[test]
+    fn swaps_the_placeholder_and_imports_the_module() {
+        let wired = wire_solvers(SOLVERS, 12, "day_12").unwrap();
+
+        assert!(wired.contains("12 => Some(Box::new(day_12::Day::default())),"));
+        assert!(!wired.contains("12 => Some(Box::new(day_x::Day::default())),"));
+        assert!(wired.contains("13 => Some(Box::new(day_x::Day::default())),"));
+        assert!(wired.contains("day_11, day_12, day_2,"));
+        assert!(wired.contains("day_x, Solver,\n}"));
+        assert_eq!(wired.matches("use crate::{").count(), 1);
+    }
+
+    #[test]
+    fn wires_days_one_after_another() {
+        let wired = wire_solvers(SOLVERS, 12, "day_12").unwrap();
+        let wired = wire_solvers(&wired, 13, "day_13").unwrap();
+
+        assert!(wired.contains("day_11, day_12, day_13, day_2,"));
+        assert!(wired.contains("13 => Some(Box::new(day_13::Day::default())),"));
+    }
+
+    #[test]
+    fn only_swaps_the_placeholder_of_the_day() {
+        let solvers = "use crate::{
+    day_x, Solver,
+};
+
+match day {
+    1 => Some(Box::new(day_x::Day::default())),
+    11 => Some(Box::new(day_x::Day::default())),
+    21 => Some(Box::new(day_x::Day::default())),
+    _ => None,
+}
+";
+        let wired = wire_solvers(solvers, 1, "day_1").unwrap();
+
+        assert_eq!(
+            added(solvers, &wired),
+            [
+                "    day_1, day_x, Solver,",
+                "    1 => Some(Box::new(day_1::Day::default())),"
+            ]
+        );
+        assert!(wired.contains("11 => Some(Box::new(day_x::Day::default())),"));
+        assert!(wired.contains("21 => Some(Box::new(day_x::Day::default())),"));
+    }
+
+    #[test]
+    fn refuses_a_wired_day() {
+        assert!(wire_solvers(SOLVERS, 1, "day_1").is_err());
+        assert!(wire_solvers(SOLVERS, 11, "day_11").is_err());
+
+        let wired = wire_solvers(SOLVERS, 12, "day_12").unwrap();
+        assert!(wire_solvers(&wired, 12, "day_12").is_err());
+    }
+}
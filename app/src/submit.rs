@@ -0,0 +1,249 @@
+use crate::aoc::{Client, Outcome};
+use anyhow::{anyhow, bail, Result};
+use solutions::provider::{Directory, Provider};
+use solutions::runner::Runner;
+use solutions::Parts;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+struct Record {
+    part: u8,
+    answer: String,
+    outcome: Outcome,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Record> {
+        let mut part = None;
+        let mut answer = None;
+        let mut outcome = None;
+
+        for field in line.split_whitespace() {
+            match field.split_once('=')? {
+                ("part", value) => part = value.parse().ok(),
+                ("answer", value) => answer = Some(value.to_string()),
+                ("outcome", value) => outcome = Outcome::from_name(value),
+                _ => {}
+            }
+        }
+
+        Some(Record {
+            part: part?,
+            answer: answer?,
+            outcome: outcome?,
+        })
+    }
+}
+
+fn read_records(path: &Path) -> Result<Vec<Record>> {
+    let log = match fs::read_to_string(path) {
+        Ok(log) => log,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(log.lines().filter_map(Record::parse).collect())
+}
+
+fn append_record(path: &Path, record: &Record) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    writeln!(
+        file,
+        "part={} answer={} outcome={}",
+        record.part,
+        record.answer,
+        record.outcome.name()
+    )?;
+
+    Ok(())
+}
+
+fn refusal(records: &[Record], part: u8, answer: &str) -> Option<String> {
+    let numeric = answer.parse::<i64>().ok();
+
+    for record in records.iter().filter(|record| record.part == part) {
+        let known = record.answer.parse::<i64>().ok();
+
+        match (&record.outcome, numeric, known) {
+            (Outcome::Correct, _, _) => {
+                return Some(format!("already solved with answer {}", record.answer))
+            }
+            (_, _, _) if record.answer == answer => {
+                return Some(format!("already submitted as {}", record.outcome.name()))
+            }
+            (Outcome::TooHigh, Some(numeric), Some(known)) if numeric >= known => {
+                return Some(format!("not below known too high answer {}", known))
+            }
+            (Outcome::TooLow, Some(numeric), Some(known)) if numeric <= known => {
+                return Some(format!("not above known too low answer {}", known))
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn solve(runner: &Runner, provider: &dyn Provider, day: usize, part: u8) -> Result<String> {
+    let parts = match part {
+        1 => Parts::One,
+        _ => Parts::Two,
+    };
+
+    let report = runner.run(day, &provider.input(day)?, parts)?;
+
+    if let Some(failure) = report.failure() {
+        bail!(
+            "day {} {} {} error={}",
+            day,
+            failure.phase.name(),
+            failure.outcome.status(),
+            failure.error().unwrap_or_default()
+        );
+    }
+
+    report
+        .answer(part)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("day {} part {} produced no answer", day, part))
+}
+
+pub fn submit(
+    runner: &Runner,
+    provider: &dyn Provider,
+    directory: &Directory,
+    base_url: &str,
+    day: usize,
+    part: u8,
+) -> Result<()> {
+    let answer = solve(runner, provider, day, part)?;
+    let path = directory.answers_path(day);
+    let records = read_records(&path)?;
+
+    if let Some(reason) = refusal(&records, part, &answer) {
+        bail!(
+            "day {} part {} answer {} not submitted: {}",
+            day,
+            part,
+            answer,
+            reason
+        );
+    }
+
+    let client = Client::new(base_url)?;
+    let outcome = client.submit(day, part, &answer)?;
+
+    let wait = match &outcome {
+        Outcome::RateLimited(wait) => format!(" wait={:?}", wait),
+        _ => String::new(),
+    };
+
+    println!(
+        "day={} part={} answer={} outcome={}{}",
+        day,
+        part,
+        answer,
+        outcome.name(),
+        wait
+    );
+
+    if !matches!(outcome, Outcome::RateLimited(_) | Outcome::AlreadySolved) {
+        append_record(
+            &path,
+            &Record {
+                part,
+                answer,
+                outcome,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    fn records(log: &str) -> Vec<Record> {
+        log.lines().filter_map(Record::parse).collect()
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let records = records("part=1 answer=42 outcome=wrong\npart=2 answer=7 outcome=correct");
+
+        assert!(refusal(&records, 1, "42").is_some());
+        assert!(refusal(&records, 1, "43").is_none());
+        assert!(refusal(&records, 2, "8").is_some());
+    }
+
+    #[test]
+    fn refuses_answers_outside_known_bounds() {
+        let records =
+            records("part=1 answer=100 outcome=too_high\npart=1 answer=10 outcome=too_low");
+
+        assert!(refusal(&records, 1, "100").is_some());
+        assert!(refusal(&records, 1, "150").is_some());
+        assert!(refusal(&records, 1, "10").is_some());
+        assert!(refusal(&records, 1, "5").is_some());
+        assert!(refusal(&records, 1, "50").is_none());
+        assert!(refusal(&records, 2, "150").is_none());
+    }
+
+    #[test]
+    fn bounds_ignore_non_numeric_answers() {
+        let records = records("part=1 answer=100 outcome=too_high");
+
+        assert!(refusal(&records, 1, "abc").is_none());
+    }
+
+    #[test]
+    fn round_trips_records() {
+        let path = env::temp_dir()
+            .join(format!("aoc-submit-{}", process::id()))
+            .join("7");
+        let _result = fs::remove_file(&path);
+
+        assert!(read_records(&path).unwrap().is_empty());
+
+        for (part, answer, outcome) in [(1, "6440", Outcome::TooLow), (2, "5905", Outcome::Correct)]
+        {
+            let record = Record {
+                part,
+                answer: answer.to_string(),
+                outcome,
+            };
+
+            append_record(&path, &record).unwrap();
+        }
+
+        let records = read_records(&path).unwrap();
+        let fields: Vec<_> = records
+            .iter()
+            .map(|record| (record.part, record.answer.as_str(), &record.outcome))
+            .collect();
+
+        assert_eq!(
+            fields,
+            [
+                (1, "6440", &Outcome::TooLow),
+                (2, "5905", &Outcome::Correct)
+            ]
+        );
+        assert!(Record::parse("part=2 answer=6440 outcome=rate_limited").is_none());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
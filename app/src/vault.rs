@@ -0,0 +1,117 @@
+use anyhow::{bail, Result};
+use solutions::provider::Directory;
+use solutions::vault::{self, Key, VaultError};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+fn write_key_file(path: &Path, key: &Key) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path)?;
+    writeln!(file, "{}", key.to_hex())
+}
+
+fn load_or_generate_key() -> Result<Key> {
+    match Key::load() {
+        Ok(key) => Ok(key),
+        Err(VaultError::NoKey(path)) => {
+            let key = Key::generate();
+            write_key_file(&path, &key)?;
+            println!("vault key generated path={}", path.display());
+
+            Ok(key)
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn read_or_empty(path: &Path) -> io::Result<Vec<u8>> {
+    match fs::read(path) {
+        Ok(data) => Ok(data),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(err),
+    }
+}
+
+fn days(day: Option<usize>) -> Vec<usize> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    }
+}
+
+pub fn seal(directory: &Directory, day: Option<usize>, remove: bool) -> Result<()> {
+    let key = load_or_generate_key()?;
+
+    for index in days(day) {
+        let input_path = directory.input_path(index);
+        let plaintext = String::from_utf8(read_or_empty(&input_path)?)?;
+
+        if plaintext.is_empty() {
+            if day.is_some() {
+                bail!("no plaintext input for day {}", index);
+            }
+            continue;
+        }
+
+        let sealed_path = directory.sealed_path(index);
+        let existing = read_or_empty(&sealed_path)?;
+
+        let unchanged = vault::unseal(&key, &existing)
+            .map(|unsealed| unsealed == plaintext)
+            .unwrap_or(false);
+
+        if unchanged {
+            println!("day={} unchanged path={}", index, sealed_path.display());
+        } else {
+            if let Some(parent) = sealed_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&sealed_path, vault::seal(&key, &plaintext))?;
+            println!("day={} sealed path={}", index, sealed_path.display());
+        }
+
+        if remove {
+            fs::remove_file(&input_path)?;
+            println!("day={} removed path={}", index, input_path.display());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn unseal(directory: &Directory, day: Option<usize>) -> Result<()> {
+    let key = Key::load()?;
+
+    for index in days(day) {
+        let sealed_path = directory.sealed_path(index);
+        let sealed = read_or_empty(&sealed_path)?;
+
+        if sealed.is_empty() {
+            if day.is_some() {
+                bail!("no sealed input for day {}", index);
+            }
+            continue;
+        }
+
+        let input_path = directory.input_path(index);
+
+        if let Some(parent) = input_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&input_path, vault::unseal(&key, &sealed)?)?;
+        println!("day={} unsealed path={}", index, input_path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::{load, print_report, Args};
+use anyhow::{bail, Result};
+use notify::{RecursiveMode, Watcher};
+use solutions::runner::RunReport;
+use std::path::{self, Path};
+use std::sync::mpsc;
+use std::time::Duration;
+
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+fn delta(current: Duration, previous: Option<Duration>) -> String {
+    match previous {
+        Some(previous) => {
+            let delta = current.as_secs_f64() - previous.as_secs_f64();
+            format!(" ({:+.3}ms)", delta * 1000.0)
+        }
+        None => String::new(),
+    }
+}
+
+fn run(args: &Args, previous: Option<&RunReport>) -> Result<RunReport> {
+    let source = load(args, args.provider().as_ref())?;
+    let report = args.runner().run(args.day, &source.input, source.parts)?;
+
+    print_report(&report, source.example.as_ref(), |phase| {
+        delta(
+            phase.duration,
+            previous.and_then(|previous| previous.duration(phase.phase)),
+        )
+    });
+
+    Ok(report)
+}
+
+fn relevant(event: &notify::Event, inputs: &[&Path], examples: &Path) -> bool {
+    event
+        .paths
+        .iter()
+        .any(|path| inputs.contains(&path.as_path()) || path.starts_with(examples))
+}
+
+pub fn watch(args: &Args) -> Result<()> {
+    let directory = args.directory();
+    let day = args.day;
+
+    // Events carry absolute paths, so relative `--inputs` paths are resolved before comparing.
+    let input = path::absolute(directory.input_path(day))?;
+    let sealed = path::absolute(directory.sealed_path(day))?;
+    let examples = path::absolute(directory.example_path(day))?;
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    let mut watching = false;
+
+    for (path, mode) in [
+        (input.parent(), RecursiveMode::NonRecursive),
+        (sealed.parent(), RecursiveMode::NonRecursive),
+        (examples.parent(), RecursiveMode::Recursive),
+    ] {
+        if let Some(path) = path.filter(|path| path.exists()) {
+            watcher.watch(path, mode)?;
+            watching = true;
+        }
+    }
+
+    if !watching {
+        bail!(
+            "nothing to watch, {} and {} do not exist",
+            input.parent().unwrap_or(&input).display(),
+            examples.parent().unwrap_or(&examples).display()
+        );
+    }
+
+    println!(
+        "day={} watching input={} examples={}",
+        day,
+        input.display(),
+        examples.display()
+    );
+
+    let mut previous = None;
+
+    loop {
+        match run(args, previous.as_ref()) {
+            Ok(report) => previous = Some(report),
+            Err(err) => println!("day={} error={}", day, err),
+        }
+
+        loop {
+            let event = receiver.recv()??;
+
+            if !relevant(&event, &[&input, &sealed], &examples) {
+                continue;
+            }
+
+            while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+
+            break;
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "src/vault.rs"]
+mod vault;
+
+#[allow(dead_code)]
+#[path = "src/expected.rs"]
+mod expected;
+
+fn day_entries(path: &Path) -> io::Result<Vec<(usize, PathBuf)>> {
+    let mut days = vec![];
+
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+
+        let Some(day) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<usize>().ok())
+        else {
+            continue;
+        };
+
+        days.push((day, path));
+    }
+
+    days.sort();
+
+    Ok(days)
+}
+
+fn non_empty_files(path: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(files),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_some() {
+            continue;
+        }
+
+        if path.is_file() && fs::metadata(&path)?.len() > 0 {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+fn unseal_input(day: usize, out: &Path, root: &Path) -> io::Result<Option<PathBuf>> {
+    let sealed_path = root.join("vault").join(day.to_string());
+
+    let sealed = match fs::read(&sealed_path) {
+        Ok(sealed) => sealed,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let unsealed = vault::Key::load().and_then(|key| vault::unseal(&key, &sealed));
+
+    let input = match unsealed {
+        Ok(input) => input,
+        Err(err) => {
+            println!("cargo:warning=day {} input not embedded: {}", day, err);
+            return Ok(None);
+        }
+    };
+
+    let path = out.join(format!("input_{}", day));
+    fs::write(&path, input)?;
+
+    Ok(Some(path))
+}
+
+fn generate_inputs(root: &Path, out: &Path) -> io::Result<String> {
+    let mut code = String::from("static INPUTS: &[(usize, &str)] = &[\n");
+
+    for day in 1..=25 {
+        let mut path = root.join("input").join(day.to_string());
+
+        if !path.is_file() || fs::metadata(&path)?.len() == 0 {
+            match unseal_input(day, out, root)? {
+                Some(unsealed) => path = unsealed,
+                None => continue,
+            }
+        }
+
+        writeln!(code, "    ({}, include_str!({:?})),", day, path).unwrap();
+    }
+
+    code.push_str("];\n");
+
+    Ok(code)
+}
+
+fn generate_examples(root: &Path) -> io::Result<String> {
+    let mut code = String::from("static EXAMPLES: &[(usize, &[Example])] = &[\n");
+
+    for (day, path) in day_entries(&root.join("example"))? {
+        let mut examples = String::new();
+
+        for (directory, parts) in [
+            (path.clone(), "Both"),
+            (path.join("part_1"), "One"),
+            (path.join("part_2"), "Two"),
+        ] {
+            for file in non_empty_files(&directory)? {
+                let name = file.file_name().unwrap().to_string_lossy();
+
+                let answers = match fs::read_to_string(file.with_extension(expected::EXTENSION)) {
+                    Ok(answers) => answers,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+                    Err(err) => return Err(err),
+                };
+
+                let (expected_1, expected_2) = expected::parse(&answers);
+                let literal = |expected: Option<String>| match expected {
+                    Some(expected) => format!("Some(Cow::Borrowed({:?}))", expected),
+                    None => "None".to_string(),
+                };
+
+                writeln!(
+                    examples,
+                    "        Example {{ name: Cow::Borrowed({:?}), parts: Parts::{}, input: Cow::Borrowed(include_str!({:?})), expected_1: {}, expected_2: {} }},",
+                    name, parts, file, literal(expected_1), literal(expected_2)
+                )
+                .unwrap();
+            }
+        }
+
+        if examples.is_empty() {
+            continue;
+        }
+
+        writeln!(code, "    ({}, &[\n{}    ]),", day, examples).unwrap();
+    }
+
+    code.push_str("];\n");
+
+    Ok(code)
+}
+
+fn main() -> io::Result<()> {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=input");
+    println!("cargo:rerun-if-changed=example");
+    println!("cargo:rerun-if-env-changed={}", vault::KEY_ENV);
+    println!("cargo:rerun-if-env-changed={}", vault::KEY_FILE_ENV);
+
+    for path in [root.join("vault"), vault::Key::file()] {
+        if path.exists() {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+
+    fs::write(out.join("input.rs"), generate_inputs(&root, &out)?)?;
+    fs::write(out.join("example.rs"), generate_examples(&root)?)?;
+
+    Ok(())
+}
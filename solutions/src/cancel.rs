@@ -0,0 +1,124 @@
+use crate::runner::Clock;
+use anyhow::{Error, Result};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CancelError {
+    #[error("timed out")]
+    TimedOut,
+}
+
+pub(crate) struct Context {
+    pub clock: Rc<dyn Clock>,
+    pub deadline: Option<Duration>,
+}
+
+impl Context {
+    fn check(&self) -> Result<()> {
+        if self
+            .deadline
+            .is_some_and(|deadline| self.clock.now() >= deadline)
+        {
+            return Err(Error::from(CancelError::TimedOut));
+        }
+
+        Ok(())
+    }
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+// Called from long running solver loops, fails once the running phase is past its
+// deadline. Outside of a runner phase it never fails.
+pub fn checkpoint() -> Result<()> {
+    CONTEXT.with(|context| match context.borrow().as_ref() {
+        Some(context) => context.check(),
+        None => Ok(()),
+    })
+}
+
+pub(crate) fn scope<T>(context: Context, f: impl FnOnce() -> T) -> T {
+    let previous = CONTEXT.with(|cell| cell.replace(Some(context)));
+    let result = f();
+    CONTEXT.with(|cell| *cell.borrow_mut() = previous);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct ManualClock {
+        now: Rc<Cell<Duration>>,
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Duration {
+            self.now.get()
+        }
+    }
+
+    fn timed_out(result: Result<()>) -> bool {
+        result
+            .err()
+            .is_some_and(|err| err.downcast_ref::<CancelError>() == Some(&CancelError::TimedOut))
+    }
+
+    #[test]
+    fn fails_once_past_the_deadline() {
+        let now = Rc::new(Cell::new(Duration::ZERO));
+
+        let context = Context {
+            clock: Rc::new(ManualClock { now: now.clone() }),
+            deadline: Some(Duration::from_secs(1)),
+        };
+
+        scope(context, || {
+            assert!(checkpoint().is_ok());
+
+            now.set(Duration::from_millis(999));
+            assert!(checkpoint().is_ok());
+
+            now.set(Duration::from_secs(1));
+            assert!(timed_out(checkpoint()));
+        });
+
+        assert!(checkpoint().is_ok());
+    }
+
+    #[test]
+    fn never_fails_without_a_deadline_or_outside_a_scope() {
+        let now = Rc::new(Cell::new(Duration::MAX));
+
+        assert!(checkpoint().is_ok());
+
+        let context = Context {
+            clock: Rc::new(ManualClock { now }),
+            deadline: None,
+        };
+
+        scope(context, || assert!(checkpoint().is_ok()));
+    }
+
+    #[test]
+    fn restores_the_enclosing_scope() {
+        let now = Rc::new(Cell::new(Duration::from_secs(2)));
+        let context = |deadline| Context {
+            clock: Rc::new(ManualClock { now: now.clone() }),
+            deadline,
+        };
+
+        scope(context(Some(Duration::from_secs(1))), || {
+            scope(context(None), || assert!(checkpoint().is_ok()));
+
+            assert!(timed_out(checkpoint()));
+        });
+    }
+}
@@ -1,4 +1,6 @@
-use crate::Solver;
+use crate::grid::{self, Style};
+use crate::visualize::{self, Frame, Visualize};
+use crate::{cancel, Solver};
 use anyhow::{Error, Result};
 use thiserror::Error;
 
@@ -46,6 +48,17 @@ enum PipeKind {
 }
 
 impl PipeKind {
+    fn glyph(&self) -> char {
+        match self {
+            PipeKind::Vertical => '│',
+            PipeKind::Horizontal => '─',
+            PipeKind::NorthEast => '└',
+            PipeKind::NorthWest => '┘',
+            PipeKind::SouthWest => '┐',
+            PipeKind::SouthEast => '┌',
+        }
+    }
+
     fn enter(&self, entry: Cardinal) -> Option<Cardinal> {
         match self {
             PipeKind::Vertical => {
@@ -181,7 +194,9 @@ impl Grid {
         self.tiles.get_mut(y).and_then(|row| row.get_mut(x))
     }
 
-    fn clean(&mut self) {
+    fn clean(&mut self) -> Result<()> {
+        cancel::checkpoint()?;
+
         let mut cleaner = false;
 
         let mut ground_positions = vec![];
@@ -205,8 +220,10 @@ impl Grid {
         }
 
         if cleaner {
-            self.clean();
+            self.clean()?;
         }
+
+        Ok(())
     }
 
     fn determine_start(&mut self) -> Result<()> {
@@ -254,7 +271,8 @@ impl Grid {
         Ok(())
     }
 
-    fn isolate_loop(&mut self) -> Result<()> {
+    // Positions of the loop in traversal order, ending back at the start.
+    fn walk_loop(&self) -> Result<Vec<Point2>> {
         let start_tile = self.tile(self.start).unwrap();
 
         let TileKind::RegularPipe(start_pipe) = &start_tile.tile_kind else {
@@ -277,6 +295,23 @@ impl Grid {
 
         let mut entry = entry.ok_or(Error::from(GridError::IndeterminateStart))?;
         let mut tile = start_tile;
+        let mut path = vec![];
+
+        loop {
+            (tile, entry) = tile.traverse(self, entry)?;
+
+            path.push(tile.position);
+
+            if tile == start_tile {
+                break;
+            }
+        }
+
+        Ok(path)
+    }
+
+    fn isolate_loop(&mut self) -> Result<()> {
+        let path = self.walk_loop()?;
 
         let mut tiles = self.tiles.clone();
 
@@ -289,46 +324,84 @@ impl Grid {
             })
         });
 
-        loop {
-            (tile, entry) = tile.traverse(self, entry)?;
-
-            tiles[tile.position.y as usize][tile.position.x as usize].tile_kind =
-                tile.tile_kind.clone();
-
-            if tile == start_tile {
-                break;
-            }
+        for position in path {
+            tiles[position.y as usize][position.x as usize].tile_kind =
+                self.tile(position).unwrap().tile_kind.clone();
         }
 
         self.tiles = tiles;
 
         Ok(())
     }
+
+    // Ground tiles inside the isolated loop, found by counting loop crossings along each row.
+    fn enclosed(&self) -> Vec<Point2> {
+        let mut enclosed_tiles = vec![];
+
+        for (y, row) in self.tiles.iter().enumerate() {
+            let mut enclosed = false;
+            let mut passed_north = false;
+
+            for (x, tile) in row.iter().enumerate() {
+                match &tile.tile_kind {
+                    tile @ TileKind::RegularPipe(pipe) => match pipe {
+                        PipeKind::Vertical => {
+                            enclosed = !enclosed;
+                        }
+                        PipeKind::NorthEast | PipeKind::SouthEast => {
+                            passed_north = *tile == TileKind::RegularPipe(PipeKind::NorthEast)
+                        }
+                        PipeKind::NorthWest | PipeKind::SouthWest => {
+                            let pass_blocker = if !passed_north {
+                                TileKind::RegularPipe(PipeKind::NorthWest)
+                            } else {
+                                TileKind::RegularPipe(PipeKind::SouthWest)
+                            };
+
+                            if pass_blocker.eq(tile) {
+                                enclosed = !enclosed;
+                            }
+                        }
+                        _ => {}
+                    },
+                    TileKind::Ground => {}
+                    _ => {
+                        unreachable!()
+                    }
+                }
+                if enclosed && self.tiles[y][x].tile_kind == TileKind::Ground {
+                    enclosed_tiles.push(tile.position);
+                }
+            }
+        }
+
+        enclosed_tiles
+    }
 }
 
 impl Tile {
-    fn east<'a>(&'a self, grid: &'a Grid) -> Option<&Tile> {
+    fn east<'a>(&'a self, grid: &'a Grid) -> Option<&'a Tile> {
         let mut position = self.position;
         position.x += 1;
 
         grid.tile(position)
     }
 
-    fn west<'a>(&'a self, grid: &'a Grid) -> Option<&Tile> {
+    fn west<'a>(&'a self, grid: &'a Grid) -> Option<&'a Tile> {
         let mut position = self.position;
         position.x -= 1;
 
         grid.tile(position)
     }
 
-    fn north<'a>(&'a self, grid: &'a Grid) -> Option<&Tile> {
+    fn north<'a>(&'a self, grid: &'a Grid) -> Option<&'a Tile> {
         let mut position = self.position;
         position.y -= 1;
 
         grid.tile(position)
     }
 
-    fn south<'a>(&'a self, grid: &'a Grid) -> Option<&Tile> {
+    fn south<'a>(&'a self, grid: &'a Grid) -> Option<&'a Tile> {
         let mut position = self.position;
         position.y += 1;
 
@@ -374,13 +447,14 @@ impl Tile {
     }
 
     fn regular_pipe(&self) -> bool {
-        match self.tile_kind {
-            TileKind::RegularPipe(_) => true,
-            _ => false,
-        }
+        matches!(self.tile_kind, TileKind::RegularPipe(_))
     }
 
-    fn traverse<'a>(&'a self, grid: &'a Grid, entrypoint: Cardinal) -> Result<(&Tile, Cardinal)> {
+    fn traverse<'a>(
+        &'a self,
+        grid: &'a Grid,
+        entrypoint: Cardinal,
+    ) -> Result<(&'a Tile, Cardinal)> {
         let leaky = Error::from(GridError::LeakyPipeSystem);
 
         let pipe = match &self.tile_kind {
@@ -435,31 +509,31 @@ impl TileKind {
 
 impl PipeKind {
     fn connects_north(&self) -> bool {
-        match self {
-            PipeKind::Vertical | PipeKind::NorthWest | PipeKind::NorthEast => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            PipeKind::Vertical | PipeKind::NorthWest | PipeKind::NorthEast
+        )
     }
 
     fn connects_west(&self) -> bool {
-        match self {
-            PipeKind::Horizontal | PipeKind::NorthWest | PipeKind::SouthWest => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            PipeKind::Horizontal | PipeKind::NorthWest | PipeKind::SouthWest
+        )
     }
 
     fn connects_east(&self) -> bool {
-        match self {
-            PipeKind::Horizontal | PipeKind::NorthEast | PipeKind::SouthEast => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            PipeKind::Horizontal | PipeKind::NorthEast | PipeKind::SouthEast
+        )
     }
 
     fn connects_south(&self) -> bool {
-        match self {
-            PipeKind::Vertical | PipeKind::SouthEast | PipeKind::SouthWest => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            PipeKind::Vertical | PipeKind::SouthEast | PipeKind::SouthWest
+        )
     }
 }
 
@@ -508,7 +582,7 @@ impl Solver for Day {
             self.grid.tiles.push(row);
         }
 
-        self.grid.clean();
+        self.grid.clean()?;
         self.grid.determine_start()?;
         self.grid.isolate_loop()?;
 
@@ -521,55 +595,72 @@ impl Solver for Day {
             .tiles
             .iter()
             .flatten()
-            .filter(|tile| match tile.tile_kind {
-                TileKind::RegularPipe(_) => true,
-                _ => false,
-            })
+            .filter(|tile| matches!(tile.tile_kind, TileKind::RegularPipe(_)))
             .count();
 
         Ok((count / 2).to_string())
     }
 
     fn part_2(&self) -> Result<String> {
-        let mut total = 0;
+        Ok(self.grid.enclosed().len().to_string())
+    }
 
-        for (y, row) in self.grid.tiles.iter().enumerate() {
-            let mut enclosed = false;
-            let mut passed_north = false;
+    fn grid(&self) -> Option<grid::Grid> {
+        let mut picture = grid::Grid::from_text(&self.input, Style::Dim);
 
-            for (x, tile) in row.iter().enumerate() {
-                match &tile.tile_kind {
-                    tile @ TileKind::RegularPipe(pipe) => match pipe {
-                        PipeKind::Vertical => {
-                            enclosed = !enclosed;
-                        }
-                        PipeKind::NorthEast | PipeKind::SouthEast => {
-                            passed_north = *tile == TileKind::RegularPipe(PipeKind::NorthEast)
-                        }
-                        PipeKind::NorthWest | PipeKind::SouthWest => {
-                            let pass_blocker = if !passed_north {
-                                TileKind::RegularPipe(PipeKind::NorthWest)
-                            } else {
-                                TileKind::RegularPipe(PipeKind::SouthWest)
-                            };
+        for tile in self.grid.tiles.iter().flatten() {
+            if let TileKind::RegularPipe(pipe) = &tile.tile_kind {
+                let (x, y) = (tile.position.x as usize, tile.position.y as usize);
+                picture.set(x, y, pipe.glyph(), Style::Highlight);
+            }
+        }
 
-                            if pass_blocker.eq(tile) {
-                                enclosed = !enclosed;
-                            }
-                        }
-                        _ => {}
-                    },
-                    TileKind::Ground => {}
-                    _ => {
-                        unreachable!()
-                    }
-                }
-                if enclosed && self.grid.tiles[y][x].tile_kind == TileKind::Ground {
-                    total += 1;
-                }
+        for position in self.grid.enclosed() {
+            picture.set_style(position.x as usize, position.y as usize, Style::Accent);
+        }
+
+        picture.label(self.grid.start.x as usize, self.grid.start.y as usize, "S");
+
+        Some(picture)
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+// The loop traced from the start tile, then the tiles it encloses.
+impl Visualize for Day {
+    fn frames(&self) -> Result<Vec<Frame>> {
+        let path = self.grid.walk_loop()?;
+        let stride = visualize::stride(path.len());
+
+        let mut picture = grid::Grid::from_text(&self.input, Style::Dim);
+        picture.label(self.grid.start.x as usize, self.grid.start.y as usize, "S");
+
+        let mut frames = vec![Frame::Grid(picture.clone())];
+
+        for (step, position) in path.iter().enumerate() {
+            cancel::checkpoint()?;
+
+            if let Some(TileKind::RegularPipe(pipe)) =
+                self.grid.tile(*position).map(|tile| &tile.tile_kind)
+            {
+                let (x, y) = (position.x as usize, position.y as usize);
+                picture.set(x, y, pipe.glyph(), Style::Highlight);
+            }
+
+            if (step + 1) % stride == 0 {
+                frames.push(Frame::Grid(picture.clone()));
             }
         }
 
-        Ok(total.to_string())
+        for position in self.grid.enclosed() {
+            picture.set_style(position.x as usize, position.y as usize, Style::Accent);
+        }
+
+        frames.push(Frame::Grid(picture));
+
+        Ok(frames)
     }
 }
@@ -1,3 +1,4 @@
+use crate::grid::{Grid, Style};
 use crate::Solver;
 use std::fmt::{Display, Formatter};
 
@@ -165,4 +166,22 @@ impl Solver for Day {
 
         Ok(image.distance().to_string())
     }
+
+    // Galaxies numbered as in the puzzle text, after the part 1 expansion.
+    fn grid(&self) -> Option<Grid> {
+        let mut image = self.image.clone();
+
+        image.expand(1);
+
+        let mut grid = Grid::new(image.width as usize, image.height as usize, '.', Style::Dim);
+
+        for (index, galaxy) in image.galaxies.iter().enumerate() {
+            let (x, y) = (galaxy.x as usize, galaxy.y as usize);
+
+            grid.set(x, y, '#', Style::Highlight);
+            grid.label(x, y, (index + 1).to_string());
+        }
+
+        Some(grid)
+    }
 }
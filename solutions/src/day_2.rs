@@ -13,26 +13,26 @@ pub struct Day {
 #[derive(Error, Debug)]
 pub enum DayError {
     #[error("split game set by whitespace delimiter")]
-    GameSetDelimiter,
+    Delimiter,
     #[error("parse game set integer string {0}")]
-    GameSetInteger(String),
+    Integer(String),
     #[error("map game set color {0}")]
-    GameSetColor(String),
+    Color(String),
 }
 
 impl Day {
     fn parse_game_set(value: &str) -> Result<GameSet> {
-        let (amount_str, color) = value.split_once(" ").ok_or(DayError::GameSetDelimiter)?;
+        let (amount_str, color) = value.split_once(" ").ok_or(DayError::Delimiter)?;
 
         let amount: u32 = amount_str
             .parse()
-            .map_err(|_err| DayError::GameSetInteger(amount_str.to_string()))?;
+            .map_err(|_err| DayError::Integer(amount_str.to_string()))?;
 
         match color {
             "blue" => Ok((0, 0, amount)),
             "red" => Ok((amount, 0, 0)),
             "green" => Ok((0, amount, 0)),
-            _ => Err(Error::from(DayError::GameSetColor(color.to_string()))),
+            _ => Err(Error::from(DayError::Color(color.to_string()))),
         }
     }
 
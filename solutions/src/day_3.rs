@@ -1,3 +1,4 @@
+use crate::grid::{Grid, Style};
 use crate::Solver;
 
 #[derive(Debug)]
@@ -47,7 +48,7 @@ impl Solver for Day {
             let mut part_value: u32 = 0;
 
             for (x, char) in line.chars().enumerate() {
-                if reading_digits && !char.is_digit(10) {
+                if reading_digits && !char.is_ascii_digit() {
                     let width = (part_value.ilog10() + 1) as usize;
                     let part_x = x as u32 - width as u32;
 
@@ -137,4 +138,26 @@ impl Solver for Day {
 
         Ok(gear_ratio_sum.to_string())
     }
+
+    fn grid(&self) -> Option<Grid> {
+        let mut grid = Grid::from_text(&self.input, Style::Plain);
+
+        for cell in grid.cells.iter_mut().filter(|cell| cell.glyph == '.') {
+            cell.style = Style::Dim;
+        }
+
+        for (x, y) in &self.symbols {
+            grid.set_style(*x as usize, *y as usize, Style::Accent);
+        }
+
+        for part in &self.part_numbers {
+            if self.symbols.iter().any(|(x, y)| part.adjacent(*x, *y)) {
+                for x in part.x..part.x + part.width as u32 {
+                    grid.set_style(x as usize, part.y as usize, Style::Highlight);
+                }
+            }
+        }
+
+        Some(grid)
+    }
 }
@@ -1,3 +1,4 @@
+use crate::visualize::{self, Frame, Visualize};
 use crate::Solver;
 
 #[derive(Clone)]
@@ -15,15 +16,70 @@ impl Card {
                 matches += 1;
             }
         }
-        return matches;
+        matches
     }
 }
+
+// Cards shown per frame, starting at the card being scored.
+const WINDOW: usize = 12;
+
 #[derive(Default)]
 pub struct Day {
     input: String,
     pile: Vec<Card>,
 }
 
+impl Day {
+    // Scores the cards in order, calling `visit` with each card and the copy counts it left.
+    fn cascade(&self, mut visit: impl FnMut(&Card, &[u32])) -> Vec<u32> {
+        let mut counts = vec![1; self.pile.len()];
+
+        for card in &self.pile {
+            let count = counts[card.number - 1];
+
+            let mut matches = card.matching_numbers();
+
+            while matches > 0 {
+                let index = card.number - 1 + matches as usize;
+
+                if index < counts.len() {
+                    counts[index] += count;
+                }
+
+                matches -= 1;
+            }
+
+            visit(card, &counts);
+        }
+
+        counts
+    }
+
+    fn describe(&self, card: &Card, counts: &[u32]) -> String {
+        let total: u32 = counts.iter().sum();
+        let mut text = format!("cards={}\n", total);
+        let won = card.matching_numbers() as usize;
+
+        for other in self.pile.iter().skip(card.number - 1).take(WINDOW) {
+            let marker = match other.number - card.number {
+                0 => '>',
+                offset if offset <= won => '+',
+                _ => ' ',
+            };
+
+            text.push_str(&format!(
+                "{} card={:<4} matches={:<3} copies={}\n",
+                marker,
+                other.number,
+                other.matching_numbers(),
+                counts[other.number - 1]
+            ));
+        }
+
+        text
+    }
+}
+
 impl Solver for Day {
     fn setup(&mut self, input: &str) {
         self.input = input.to_string();
@@ -38,11 +94,11 @@ impl Solver for Day {
             let mut winning = vec![];
             let mut owned = vec![];
 
-            for number in left.trim().split_whitespace() {
+            for number in left.split_whitespace() {
                 winning.push(number.parse()?);
             }
 
-            for number in right.trim().split_whitespace() {
+            for number in right.split_whitespace() {
                 owned.push(number.parse()?);
             }
 
@@ -71,30 +127,28 @@ impl Solver for Day {
     }
 
     fn part_2(&self) -> anyhow::Result<String> {
-        let mut counts = vec![];
-
-        for _ in &self.pile {
-            counts.push(1);
-        }
+        let sum: u32 = self.cascade(|_, _| {}).iter().sum();
 
-        for card in &self.pile {
-            let count = counts[card.number - 1];
-
-            let mut matches = card.matching_numbers();
+        Ok(sum.to_string())
+    }
 
-            while matches > 0 {
-                let index = card.number - 1 + matches as usize;
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
 
-                if index < counts.len() {
-                    counts[index] += count;
-                }
+// The copies each card wins, cascading down the pile.
+impl Visualize for Day {
+    fn frames(&self) -> anyhow::Result<Vec<Frame>> {
+        let stride = visualize::stride(self.pile.len());
+        let mut frames = vec![];
 
-                matches -= 1;
+        self.cascade(|card, counts| {
+            if card.number % stride == 0 || card.number == self.pile.len() {
+                frames.push(Frame::Text(self.describe(card, counts)));
             }
-        }
+        });
 
-        let sum: u32 = counts.iter().sum();
-
-        Ok(sum.to_string())
+        Ok(frames)
     }
 }
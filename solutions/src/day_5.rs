@@ -74,7 +74,7 @@ impl Map {
         for input in inputs {
             let mut mapped = self.map_range(input);
 
-            mapped.sort_by(|a, b| a.start.cmp(&b.start));
+            mapped.sort_by_key(|a| a.start);
 
             for range in mapped {
                 output.push(range);
@@ -106,9 +106,8 @@ impl Day {
         let light = self.water.map_one(water);
         let temperature = self.light.map_one(light);
         let humidity = self.temperature.map_one(temperature);
-        let location = self.humidity.map_one(humidity);
 
-        location
+        self.humidity.map_one(humidity)
     }
 
     fn location_ranges(&self, seed_ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
@@ -118,9 +117,8 @@ impl Day {
         let light = self.water.map_ranges(water);
         let temperature = self.light.map_ranges(light);
         let humidity = self.temperature.map_ranges(temperature);
-        let location = self.humidity.map_ranges(humidity);
 
-        location
+        self.humidity.map_ranges(humidity)
     }
 }
 
@@ -135,7 +133,6 @@ impl Solver for Day {
         let seed_line = lines.next().expect("seed line");
         let seed_line = seed_line.split_once(": ").unwrap().1;
         self.seeds = seed_line
-            .trim()
             .split_whitespace()
             .map(|s| s.parse::<usize>().unwrap())
             .collect();
@@ -154,8 +151,7 @@ impl Solver for Day {
                 line = lines.next().unwrap_or_default();
             }
 
-            map.mappings
-                .sort_by(|a, b| a.range.start.cmp(&b.range.start));
+            map.mappings.sort_by_key(|a| a.range.start);
 
             lines.next();
         }
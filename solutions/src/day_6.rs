@@ -76,7 +76,7 @@ impl Solver for Day {
                 0
             };
             record_time += race.time * 10u64.pow(time_numbers);
-            record_distance += race.record_distance as u64 * 10u64.pow(distance_numbers);
+            record_distance += race.record_distance * 10u64.pow(distance_numbers);
         }
 
         let ways = ways(record_time as f64, record_distance as f64);
@@ -66,7 +66,7 @@ impl CamelCard {
 
 impl PartialOrd for CamelCard {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.value().partial_cmp(&other.value())
+        Some(self.cmp(other))
     }
 }
 
@@ -174,7 +174,7 @@ impl Hand {
 
         kinds[0] += jokers;
 
-        let hand_type = match (kinds[0], kinds[1]) {
+        match (kinds[0], kinds[1]) {
             (5, _) => FiveOfAKind,
             (4, _) => FourOfAKind,
             (3, 2) => FullHouse,
@@ -182,22 +182,13 @@ impl Hand {
             (2, 2) => TwoPair,
             (2, _) => OnePair,
             _ => HighCard,
-        };
-
-        hand_type
+        }
     }
 }
 
 impl PartialOrd<Self> for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let self_type = self.hand_type;
-        let other_type = other.hand_type;
-
-        if self_type != other_type {
-            return self_type.partial_cmp(&other_type);
-        }
-
-        self.cards.partial_cmp(&other.cards)
+        Some(self.cmp(other))
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::Solver;
+use crate::{cancel, Solver};
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
@@ -72,9 +72,9 @@ pub struct Day {
 
 fn ghost_endpoints(
     id: &str,
-    instructions: &Vec<Instruction>,
+    instructions: &[Instruction],
     nodes: &HashMap<String, Node>,
-) -> Vec<usize> {
+) -> Result<Vec<usize>> {
     let instruction_set_size = instructions.len();
     let mut node = nodes.get(id).unwrap();
     let mut endpoints = vec![];
@@ -86,6 +86,8 @@ fn ghost_endpoints(
     let mut instruction_index = program_counter % instruction_set_size;
 
     loop {
+        cancel::checkpoint()?;
+
         let instruction = &instructions[instruction_index];
 
         match instruction {
@@ -106,7 +108,7 @@ fn ghost_endpoints(
 
         seen.insert((instruction_index, &node.id));
     }
-    endpoints
+    Ok(endpoints)
 }
 
 impl Solver for Day {
@@ -179,7 +181,7 @@ impl Solver for Day {
 
         for node in self.nodes.values() {
             if node.ghost_start {
-                for index in ghost_endpoints(&node.id, &self.instructions, &self.nodes) {
+                for index in ghost_endpoints(&node.id, &self.instructions, &self.nodes)? {
                     all_endpoints.push(index + 1)
                 }
             }
@@ -75,7 +75,7 @@ impl Solver for Day {
         let mut all_sums = 0;
 
         for report in &self.reports {
-            let sum = predict(&report, false);
+            let sum = predict(report, false);
             all_sums += sum;
         }
 
@@ -86,7 +86,7 @@ impl Solver for Day {
         let mut all_sums = 0;
 
         for report in &self.reports {
-            let sum = predict(&report, true);
+            let sum = predict(report, true);
             all_sums += sum;
         }
 
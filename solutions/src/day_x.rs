@@ -21,4 +21,8 @@ impl Solver for Day {
     fn part_2(&self) -> anyhow::Result<String> {
         Ok("Placeholder".into())
     }
+
+    fn placeholder(&self) -> bool {
+        true
+    }
 }
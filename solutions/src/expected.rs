@@ -0,0 +1,39 @@
+pub const EXTENSION: &str = "answer";
+
+pub fn parse(text: &str) -> (Option<String>, Option<String>) {
+    let mut expected_1 = None;
+    let mut expected_2 = None;
+
+    for line in text.lines() {
+        let mut part = None;
+        let mut answer = None;
+
+        for field in line.split_whitespace() {
+            match field.split_once('=') {
+                Some(("part", value)) => part = Some(value),
+                Some(("answer", value)) => answer = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        match part {
+            Some("1") => expected_1 = answer,
+            Some("2") => expected_2 = answer,
+            _ => {}
+        }
+    }
+
+    (expected_1, expected_2)
+}
+
+pub fn format(expected_1: Option<&str>, expected_2: Option<&str>) -> String {
+    let mut text = String::new();
+
+    for (part, answer) in [(1, expected_1), (2, expected_2)] {
+        if let Some(answer) = answer {
+            text.push_str(&format!("part={} answer={}\n", part, answer));
+        }
+    }
+
+    text
+}
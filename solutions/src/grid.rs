@@ -0,0 +1,82 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Style {
+    #[default]
+    Plain,
+    Dim,
+    Highlight,
+    Accent,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Cell {
+    pub glyph: char,
+    pub style: Style,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Label {
+    pub x: usize,
+    pub y: usize,
+    pub text: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<Cell>,
+    pub labels: Vec<Label>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize, glyph: char, style: Style) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell { glyph, style }; width * height],
+            labels: vec![],
+        }
+    }
+
+    // Lines shorter than the longest one are padded with blanks.
+    pub fn from_text(text: &str, style: Style) -> Self {
+        let width = text.lines().map(|line| line.chars().count()).max();
+        let mut grid = Grid::new(width.unwrap_or(0), text.lines().count(), ' ', style);
+
+        for (y, line) in text.lines().enumerate() {
+            for (x, glyph) in line.chars().enumerate() {
+                grid.set(x, y, glyph, style);
+            }
+        }
+
+        grid
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> Option<Cell> {
+        self.cells.get(self.index(x, y)?).copied()
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, glyph: char, style: Style) {
+        if let Some(index) = self.index(x, y) {
+            self.cells[index] = Cell { glyph, style };
+        }
+    }
+
+    pub fn set_style(&mut self, x: usize, y: usize, style: Style) {
+        if let Some(index) = self.index(x, y) {
+            self.cells[index].style = style;
+        }
+    }
+
+    pub fn label(&mut self, x: usize, y: usize, text: impl Into<String>) {
+        self.labels.push(Label {
+            x,
+            y,
+            text: text.into(),
+        });
+    }
+}
@@ -1,4 +1,10 @@
 use anyhow::Result;
+use grid::Grid;
+use std::borrow::Cow;
+use std::str::FromStr;
+use thiserror::Error;
+use visualize::Visualize;
+pub mod cancel;
 mod day_1;
 mod day_10;
 mod day_11;
@@ -11,13 +17,105 @@ mod day_7;
 mod day_8;
 mod day_9;
 mod day_x;
+#[cfg(feature = "embedded")]
 pub mod example;
+pub mod expected;
+pub mod grid;
+#[cfg(feature = "embedded")]
 pub mod input;
+pub mod provider;
+pub mod runner;
 pub mod solvers;
+#[cfg(feature = "vault")]
+pub mod vault;
+pub mod visualize;
 
 pub trait Solver {
     fn setup(&mut self, input: &str);
     fn parse(&mut self) -> Result<()>;
     fn part_1(&self) -> Result<String>;
     fn part_2(&self) -> Result<String>;
+
+    // Picture of the parsed input for the web visualizer, only asked for after a successful parse.
+    fn grid(&self) -> Option<Grid> {
+        None
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        None
+    }
+
+    // Days without a solution yet are served by the `day_x` placeholder.
+    fn placeholder(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn part_1(self) -> bool {
+        matches!(self, Parts::One | Parts::Both)
+    }
+
+    pub fn part_2(self) -> bool {
+        matches!(self, Parts::Two | Parts::Both)
+    }
+
+    pub fn intersect(self, other: Parts) -> Option<Parts> {
+        match (
+            self.part_1() && other.part_1(),
+            self.part_2() && other.part_2(),
+        ) {
+            (true, true) => Some(Parts::Both),
+            (true, false) => Some(Parts::One),
+            (false, true) => Some(Parts::Two),
+            (false, false) => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Example {
+    pub name: Cow<'static, str>,
+    pub parts: Parts,
+    pub input: Cow<'static, str>,
+    pub expected_1: Option<Cow<'static, str>>,
+    pub expected_2: Option<Cow<'static, str>>,
+}
+
+impl Example {
+    pub fn applies_to(&self, parts: Parts) -> bool {
+        self.parts.intersect(parts).is_some()
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.expected_1.as_deref(),
+            2 => self.expected_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("parts must be one of 1, 2 or both, got {0}")]
+pub struct ParsePartsError(String);
+
+impl FromStr for Parts {
+    type Err = ParsePartsError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "1" => Ok(Parts::One),
+            "2" => Ok(Parts::Two),
+            "both" => Ok(Parts::Both),
+            _ => Err(ParsePartsError(value.to_string())),
+        }
+    }
 }
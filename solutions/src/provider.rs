@@ -0,0 +1,218 @@
+use crate::{expected, Example, Parts};
+use anyhow::{Error, Result};
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProviderError {
+    #[error("no input for day {0}")]
+    MissingInput(usize),
+    #[error("no example {1} for day {0}")]
+    MissingExample(usize, String),
+    #[error("read {0}: {1}")]
+    Read(PathBuf, io::Error),
+    #[error("write {0}: {1}")]
+    Write(PathBuf, io::Error),
+}
+
+pub trait Provider {
+    fn input(&self, day: usize) -> Result<String>;
+    fn examples(&self, day: usize) -> Result<Vec<Example>>;
+
+    fn example(&self, day: usize, name: &str) -> Result<Example> {
+        self.examples(day)?
+            .into_iter()
+            .find(|example| example.name == name)
+            .ok_or(Error::from(ProviderError::MissingExample(
+                day,
+                name.to_string(),
+            )))
+    }
+}
+
+pub struct Directory {
+    root: PathBuf,
+}
+
+impl Default for Directory {
+    fn default() -> Self {
+        Self::new(env!("CARGO_MANIFEST_DIR"))
+    }
+}
+
+impl Directory {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn input_path(&self, day: usize) -> PathBuf {
+        self.root.join("input").join(day.to_string())
+    }
+
+    pub fn sealed_path(&self, day: usize) -> PathBuf {
+        self.root.join("vault").join(day.to_string())
+    }
+
+    pub fn write_example(&self, day: usize, example: &Example) -> Result<PathBuf> {
+        let mut directory = self.example_path(day);
+
+        match example.parts {
+            Parts::One => directory.push("part_1"),
+            Parts::Two => directory.push("part_2"),
+            Parts::Both => {}
+        }
+
+        let path = directory.join(example.name.as_ref());
+        let answers_path = path.with_extension(expected::EXTENSION);
+        let answers = expected::format(example.expected(1), example.expected(2));
+
+        let write = |path: &Path, contents: &str| {
+            fs::write(path, contents).map_err(|err| ProviderError::Write(path.to_path_buf(), err))
+        };
+
+        fs::create_dir_all(&directory)
+            .map_err(|err| ProviderError::Write(directory.clone(), err))?;
+        write(&path, &example.input)?;
+
+        if !answers.is_empty() {
+            write(&answers_path, &answers)?;
+        }
+
+        Ok(path)
+    }
+
+    pub fn answers_path(&self, day: usize) -> PathBuf {
+        self.root.join("answers").join(day.to_string())
+    }
+
+    pub fn example_path(&self, day: usize) -> PathBuf {
+        self.root.join("example").join(day.to_string())
+    }
+
+    fn read_examples(path: &Path, parts: Parts, examples: &mut Vec<Example>) -> Result<()> {
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(Error::from(ProviderError::Read(path.to_path_buf(), err))),
+        };
+
+        let mut paths = vec![];
+
+        for entry in entries {
+            let entry = entry.map_err(|err| ProviderError::Read(path.to_path_buf(), err))?;
+            let path = entry.path();
+
+            if path.is_file() && path.extension().is_none() {
+                paths.push(path);
+            }
+        }
+
+        paths.sort();
+
+        for path in paths {
+            let input =
+                fs::read_to_string(&path).map_err(|err| ProviderError::Read(path.clone(), err))?;
+
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            if input.is_empty() {
+                continue;
+            }
+
+            let answers_path = path.with_extension(expected::EXTENSION);
+            let answers = match fs::read_to_string(&answers_path) {
+                Ok(answers) => answers,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+                Err(err) => return Err(Error::from(ProviderError::Read(answers_path, err))),
+            };
+
+            let (expected_1, expected_2) = expected::parse(&answers);
+
+            examples.push(Example {
+                name: Cow::Owned(name.to_string()),
+                parts,
+                input: Cow::Owned(input),
+                expected_1: expected_1.map(Cow::Owned),
+                expected_2: expected_2.map(Cow::Owned),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "vault")]
+impl Directory {
+    fn unseal_input(&self, day: usize) -> Result<String> {
+        use crate::vault::{self, Key};
+
+        let path = self.sealed_path(day);
+
+        let sealed = match fs::read(&path) {
+            Ok(sealed) => sealed,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::from(ProviderError::MissingInput(day)))
+            }
+            Err(err) => return Err(Error::from(ProviderError::Read(path, err))),
+        };
+
+        let key = Key::load()?;
+
+        Ok(vault::unseal(&key, &sealed)?)
+    }
+}
+
+impl Provider for Directory {
+    fn input(&self, day: usize) -> Result<String> {
+        let path = self.input_path(day);
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(Error::from(ProviderError::Read(path, err))),
+        };
+
+        #[cfg(feature = "vault")]
+        if input.is_empty() {
+            return self.unseal_input(day);
+        }
+
+        if input.is_empty() {
+            return Err(Error::from(ProviderError::MissingInput(day)));
+        }
+
+        Ok(input)
+    }
+
+    fn examples(&self, day: usize) -> Result<Vec<Example>> {
+        let path = self.example_path(day);
+        let mut examples = vec![];
+
+        Directory::read_examples(&path, Parts::Both, &mut examples)?;
+        Directory::read_examples(&path.join("part_1"), Parts::One, &mut examples)?;
+        Directory::read_examples(&path.join("part_2"), Parts::Two, &mut examples)?;
+
+        Ok(examples)
+    }
+}
+
+#[cfg(feature = "embedded")]
+pub struct Embedded;
+
+#[cfg(feature = "embedded")]
+impl Provider for Embedded {
+    fn input(&self, day: usize) -> Result<String> {
+        crate::input::get(day)
+            .map(str::to_string)
+            .ok_or(Error::from(ProviderError::MissingInput(day)))
+    }
+
+    fn examples(&self, day: usize) -> Result<Vec<Example>> {
+        Ok(crate::example::all(day).to_vec())
+    }
+}
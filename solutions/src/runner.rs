@@ -0,0 +1,517 @@
+use crate::cancel::{self, CancelError};
+use crate::solvers::Solvers;
+use crate::{Parts, Solver};
+use anyhow::{Error, Result};
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::panic::Location;
+use std::rc::Rc;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RunnerError {
+    #[error("no solver for day {0}")]
+    NoSolver(usize),
+}
+
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct SystemClock {
+    origin: std::time::Instant,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            origin: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "solve part 1",
+            Phase::Part2 => "solve part 2",
+        }
+    }
+
+    pub fn part(self) -> Option<u8> {
+        match self {
+            Phase::Parse => None,
+            Phase::Part1 => Some(1),
+            Phase::Part2 => Some(2),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Panic {
+    pub fn from_payload(payload: &(dyn Any + Send), location: Option<&Location>) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_string());
+
+        Self {
+            message,
+            location: location.map(|location| location.to_string()),
+        }
+    }
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {}", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod isolate {
+    use super::Panic;
+    use std::cell::{Cell, RefCell};
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Once;
+
+    static HOOK: Once = Once::new();
+
+    thread_local! {
+        static CATCHING: Cell<bool> = const { Cell::new(false) };
+        static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+    }
+
+    fn install() {
+        HOOK.call_once(|| {
+            let previous = panic::take_hook();
+
+            panic::set_hook(Box::new(move |info| {
+                if !CATCHING.with(Cell::get) {
+                    return previous(info);
+                }
+
+                let panic = Panic::from_payload(info.payload(), info.location());
+
+                CAUGHT.with(|caught| *caught.borrow_mut() = Some(panic));
+            }));
+        });
+    }
+
+    pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+        install();
+
+        let catching = CATCHING.with(|catching| catching.replace(true));
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        CATCHING.with(|cell| cell.set(catching));
+
+        result.map_err(|_| {
+            CAUGHT
+                .with(|caught| caught.borrow_mut().take())
+                .unwrap_or_else(|| Panic {
+                    message: "unknown panic".to_string(),
+                    location: None,
+                })
+        })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod isolate {
+    use super::Panic;
+
+    pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+        Ok(f())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Done(Option<String>),
+    Failed(String),
+    Panicked(Panic),
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Done(_) => "solved",
+            Outcome::Failed(_) => "failed",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::TimedOut(_) => "timed out",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    pub duration: Duration,
+    pub outcome: Outcome,
+}
+
+impl PhaseReport {
+    pub fn error(&self) -> Option<String> {
+        match &self.outcome {
+            Outcome::Done(_) => None,
+            Outcome::Failed(error) => Some(error.clone()),
+            Outcome::Panicked(panic) => Some(panic.to_string()),
+            Outcome::TimedOut(timeout) => Some(format!("timed out after {:?}", timeout)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RunReport {
+    pub day: usize,
+    pub phases: Vec<PhaseReport>,
+}
+
+impl RunReport {
+    pub fn phase(&self, phase: Phase) -> Option<&PhaseReport> {
+        self.phases.iter().find(|report| report.phase == phase)
+    }
+
+    pub fn duration(&self, phase: Phase) -> Option<Duration> {
+        self.phase(phase).map(|report| report.duration)
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.phases
+            .iter()
+            .find(|report| report.phase.part() == Some(part))
+            .and_then(|report| match &report.outcome {
+                Outcome::Done(answer) => answer.as_deref(),
+                _ => None,
+            })
+    }
+
+    pub fn failure(&self) -> Option<&PhaseReport> {
+        self.phases.iter().find(|report| report.error().is_some())
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.failure() {
+            Some(failure) => failure.outcome.status(),
+            None => "solved",
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|report| report.duration).sum()
+    }
+}
+
+pub struct Runner {
+    clock: Rc<dyn Clock>,
+    timeout: Option<Duration>,
+}
+
+impl Runner {
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        Self {
+            clock: Rc::new(clock),
+            timeout: None,
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn phase(&self, solver: &mut dyn Solver, phase: Phase) -> PhaseReport {
+        let time_start = self.clock.now();
+
+        let context = cancel::Context {
+            clock: self.clock.clone(),
+            deadline: self
+                .timeout
+                .and_then(|timeout| time_start.checked_add(timeout)),
+        };
+
+        let result = cancel::scope(context, || {
+            isolate::catch(|| match phase {
+                Phase::Parse => solver.parse().map(|_| None),
+                Phase::Part1 => solver.part_1().map(Some),
+                Phase::Part2 => solver.part_2().map(Some),
+            })
+        });
+
+        let duration = self.clock.now().saturating_sub(time_start);
+
+        // Phases without checkpoints only notice the deadline once they return.
+        let overran = self.timeout.filter(|timeout| duration > *timeout);
+
+        let outcome = match (result, overran) {
+            (Err(panic), _) => Outcome::Panicked(panic),
+            (_, Some(timeout)) => Outcome::TimedOut(timeout),
+            (Ok(Ok(answer)), None) => Outcome::Done(answer),
+            (Ok(Err(err)), None) => match err.downcast_ref::<CancelError>() {
+                Some(CancelError::TimedOut) => Outcome::TimedOut(self.timeout.unwrap_or(duration)),
+                None => Outcome::Failed(err.to_string()),
+            },
+        };
+
+        PhaseReport {
+            phase,
+            duration,
+            outcome,
+        }
+    }
+
+    pub fn run(&self, day: usize, input: &str, parts: Parts) -> Result<RunReport> {
+        self.run_with(day, input, parts, |_solver| {})
+    }
+
+    // Like `run`, but hands the parsed solver to `inspect` before the parts are solved, so
+    // callers can draw it without parsing again. `inspect` is not timed, and a panic in it is
+    // reported as a panic of the parse phase.
+    pub fn run_with(
+        &self,
+        day: usize,
+        input: &str,
+        parts: Parts,
+        inspect: impl FnOnce(&dyn Solver),
+    ) -> Result<RunReport> {
+        let mut solver = Solvers::get(day).ok_or(Error::from(RunnerError::NoSolver(day)))?;
+
+        solver.setup(input);
+
+        let mut report = RunReport {
+            day,
+            phases: vec![],
+        };
+
+        let mut parse = self.phase(solver.as_mut(), Phase::Parse);
+
+        if parse.error().is_none() {
+            if let Err(panic) = isolate::catch(|| inspect(solver.as_ref())) {
+                parse.outcome = Outcome::Panicked(panic);
+            }
+        }
+
+        let failed = parse.error().is_some();
+        report.phases.push(parse);
+
+        if failed {
+            return Ok(report);
+        }
+
+        let mut phases = vec![];
+
+        if parts.part_1() {
+            phases.push(Phase::Part1);
+        }
+
+        if parts.part_2() {
+            phases.push(Phase::Part2);
+        }
+
+        for phase in phases {
+            let phase_report = self.phase(solver.as_mut(), phase);
+            let failed = phase_report.error().is_some();
+
+            report.phases.push(phase_report);
+
+            if failed {
+                break;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const STEP: Duration = Duration::from_millis(10);
+
+    const DAY_1: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+    // Every reading advances time by `STEP`, so a phase without checkpoints lasts one step.
+    #[derive(Default)]
+    struct StepClock {
+        now: Cell<Duration>,
+    }
+
+    impl Clock for StepClock {
+        fn now(&self) -> Duration {
+            let now = self.now.get();
+            self.now.set(now + STEP);
+
+            now
+        }
+    }
+
+    fn runner() -> Runner {
+        Runner::new(StepClock::default())
+    }
+
+    fn phases(report: &RunReport) -> Vec<Phase> {
+        report.phases.iter().map(|report| report.phase).collect()
+    }
+
+    #[test]
+    fn times_every_phase() {
+        let report = runner().run(1, DAY_1, Parts::One).unwrap();
+
+        assert_eq!(report.day, 1);
+        assert_eq!(report.duration(Phase::Parse), Some(STEP));
+        assert_eq!(report.duration(Phase::Part1), Some(STEP));
+        assert_eq!(report.duration(Phase::Part2), None);
+        assert_eq!(report.total(), STEP * 2);
+    }
+
+    #[test]
+    fn runs_selected_parts() {
+        assert_eq!(
+            phases(&runner().run(1, DAY_1, Parts::One).unwrap()),
+            [Phase::Parse, Phase::Part1]
+        );
+        assert_eq!(
+            phases(&runner().run(1, DAY_1, Parts::Two).unwrap()),
+            [Phase::Parse, Phase::Part2]
+        );
+        assert_eq!(
+            phases(&runner().run(1, DAY_1, Parts::Both).unwrap()),
+            [Phase::Parse, Phase::Part1, Phase::Part2]
+        );
+    }
+
+    #[test]
+    fn reports_answers() {
+        let report = runner().run(1, DAY_1, Parts::One).unwrap();
+
+        assert_eq!(report.answer(1), Some("142"));
+        assert_eq!(report.answer(2), None);
+        assert!(report.failure().is_none());
+        assert_eq!(report.status(), "solved");
+    }
+
+    #[test]
+    fn stops_after_the_first_failing_phase() {
+        let report = runner().run(2, "Game 1: 3 purple\n", Parts::Both).unwrap();
+
+        assert_eq!(phases(&report), [Phase::Parse]);
+        assert_eq!(report.status(), "failed");
+        assert_eq!(report.answer(1), None);
+
+        let failure = report.failure().unwrap();
+        assert_eq!(failure.phase, Phase::Parse);
+        assert_eq!(
+            failure.error().as_deref(),
+            Some("map game set color purple")
+        );
+    }
+
+    #[test]
+    fn times_out_phases_that_overrun_without_checkpoints() {
+        let report = runner()
+            .with_timeout(Some(STEP * 2))
+            .run(1, DAY_1, Parts::Both)
+            .unwrap();
+        assert_eq!(report.status(), "solved");
+
+        let report = runner()
+            .with_timeout(Some(STEP / 2))
+            .run(1, DAY_1, Parts::Both)
+            .unwrap();
+
+        assert_eq!(phases(&report), [Phase::Parse]);
+        assert_eq!(report.status(), "timed out");
+        assert!(matches!(
+            report.phases[0].outcome,
+            Outcome::TimedOut(timeout) if timeout == STEP / 2
+        ));
+    }
+
+    #[test]
+    fn isolates_solver_panics() {
+        let report = runner().run(5, "", Parts::Both).unwrap();
+
+        assert_eq!(phases(&report), [Phase::Parse]);
+        assert_eq!(report.status(), "panicked");
+
+        let Outcome::Panicked(panic) = &report.phases[0].outcome else {
+            panic!("expected a panic, got {:?}", report.phases[0].outcome);
+        };
+
+        assert_eq!(panic.message, "seed line");
+        assert!(panic
+            .location
+            .as_deref()
+            .is_some_and(|location| location.contains("day_5.rs")));
+
+        // The hook and the thread stay usable after a caught panic.
+        let report = runner().run(1, DAY_1, Parts::One).unwrap();
+        assert_eq!(report.answer(1), Some("142"));
+
+        let report = runner().run(5, "", Parts::Both).unwrap();
+        assert_eq!(report.status(), "panicked");
+    }
+
+    #[test]
+    fn inspects_the_parsed_solver_once() {
+        let mut inspected = 0;
+
+        let report = runner()
+            .run_with(11, "#.\n..\n", Parts::One, |solver| {
+                inspected += 1;
+                assert!(solver.grid().is_some());
+            })
+            .unwrap();
+
+        assert_eq!(inspected, 1);
+        assert_eq!(report.duration(Phase::Parse), Some(STEP));
+
+        let report = runner()
+            .run_with(2, "Game 1: 3 purple\n", Parts::Both, |_solver| {
+                panic!("inspected a failed parse")
+            })
+            .unwrap();
+
+        assert_eq!(report.status(), "failed");
+    }
+
+    #[test]
+    fn rejects_days_without_a_solver() {
+        let err = runner().run(26, "", Parts::Both).unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<RunnerError>(),
+            Some(RunnerError::NoSolver(26))
+        ));
+    }
+}
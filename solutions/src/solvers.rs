@@ -2,9 +2,52 @@ use crate::{
     day_1, day_10, day_11, day_2, day_3, day_4, day_5, day_6, day_7, day_8, day_9, day_x, Solver,
 };
 
+const TITLES: [&str; 25] = [
+    "Trebuchet?!",
+    "Cube Conundrum",
+    "Gear Ratios",
+    "Scratchcards",
+    "If You Give A Seed A Fertilizer",
+    "Wait For It",
+    "Camel Cards",
+    "Haunted Wasteland",
+    "Mirage Maintenance",
+    "Pipe Maze",
+    "Cosmic Expansion",
+    "Hot Springs",
+    "Point of Incidence",
+    "Parabolic Reflector Dish",
+    "Lens Library",
+    "The Floor Will Be Lava",
+    "Clumsy Crucible",
+    "Lavaduct Lagoon",
+    "Aplenty",
+    "Pulse Propagation",
+    "Step Counter",
+    "Sand Slabs",
+    "A Long Walk",
+    "Never Tell Me The Odds",
+    "Snowverse",
+];
+
 pub struct Solvers;
 
 impl Solvers {
+    pub fn title(day: usize) -> Option<&'static str> {
+        TITLES.get(day.checked_sub(1)?).copied()
+    }
+
+    pub fn implemented(day: usize) -> bool {
+        Solvers::get(day).is_some_and(|solver| !solver.placeholder())
+    }
+
+    // Days with a real solver, in order.
+    pub fn days() -> Vec<usize> {
+        (1..=TITLES.len())
+            .filter(|day| Solvers::implemented(*day))
+            .collect()
+    }
+
     pub fn get(day: usize) -> Option<Box<dyn Solver>> {
         match day {
             1 => {
@@ -0,0 +1,113 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+pub const KEY_ENV: &str = "AOC_VAULT_KEY";
+pub const KEY_FILE_ENV: &str = "AOC_VAULT_KEY_FILE";
+
+const NONCE_SIZE: usize = 24;
+
+#[derive(Error, Debug)]
+pub enum VaultError {
+    #[error("no vault key, set {KEY_ENV} or create key file {0}")]
+    NoKey(PathBuf),
+    #[error("read key file {0}: {1}")]
+    KeyFile(PathBuf, io::Error),
+    #[error("vault key must be 64 hex characters")]
+    BadKey,
+    #[error("sealed data is truncated")]
+    Truncated,
+    #[error("unseal failed, wrong key or tampered data")]
+    Decrypt,
+    #[error("unsealed data is not utf-8")]
+    Utf8,
+}
+
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(value: &str) -> Result<Self, VaultError> {
+        let value = value.trim();
+
+        if value.len() != 64 || !value.is_ascii() {
+            return Err(VaultError::BadKey);
+        }
+
+        let mut key = [0; 32];
+
+        for (index, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&value[index * 2..index * 2 + 2], 16)
+                .map_err(|_err| VaultError::BadKey)?;
+        }
+
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub fn file() -> PathBuf {
+        match env::var_os(KEY_FILE_ENV) {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join(".vault-key"),
+        }
+    }
+
+    pub fn load() -> Result<Self, VaultError> {
+        if let Ok(value) = env::var(KEY_ENV) {
+            return Key::from_hex(&value);
+        }
+
+        let path = Key::file();
+
+        match fs::read_to_string(&path) {
+            Ok(value) => Key::from_hex(&value),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Err(VaultError::NoKey(path)),
+            Err(err) => Err(VaultError::KeyFile(path, err)),
+        }
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+pub fn seal(key: &Key, plaintext: &str) -> Vec<u8> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("encrypt in memory buffer");
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend(ciphertext);
+
+    sealed
+}
+
+pub fn unseal(key: &Key, sealed: &[u8]) -> Result<String, VaultError> {
+    if sealed.len() < NONCE_SIZE {
+        return Err(VaultError::Truncated);
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(NONCE_SIZE);
+
+    let plaintext = key
+        .cipher()
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_err| VaultError::Decrypt)?;
+
+    String::from_utf8(plaintext).map_err(|_err| VaultError::Utf8)
+}
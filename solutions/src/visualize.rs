@@ -0,0 +1,22 @@
+use crate::grid::Grid;
+use anyhow::Result;
+
+// Long runs are sampled down to about this many frames.
+pub const MAX_FRAMES: usize = 120;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Frame {
+    Grid(Grid),
+    Text(String),
+}
+
+// Opt-in for solvers that can replay their algorithm, exposed through `Solver::visualize`.
+pub trait Visualize {
+    // Frames of the algorithm on the parsed input, in order.
+    fn frames(&self) -> Result<Vec<Frame>>;
+}
+
+// Steps between recorded frames so that `steps` fit in `MAX_FRAMES`.
+pub fn stride(steps: usize) -> usize {
+    steps.div_ceil(MAX_FRAMES).max(1)
+}
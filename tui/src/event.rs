@@ -0,0 +1,52 @@
+use crate::state::State;
+use base64::Engine;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::io::{self, Write};
+
+pub enum Flow {
+    Continue,
+    Quit,
+}
+
+fn copy(state: &mut State, part: u8) {
+    let solution = match part {
+        1 => state.part_1.clone(),
+        _ => state.part_2.clone(),
+    };
+
+    let Some(solution) = solution else {
+        state.status = format!("part {} has no answer to copy", part);
+        return;
+    };
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&solution);
+    let mut stdout = io::stdout();
+
+    let written = write!(stdout, "\x1b]52;c;{}\x07", encoded).and_then(|_| stdout.flush());
+
+    state.status = match written {
+        Ok(()) => format!("part {} copied {}", part, solution),
+        Err(err) => format!("part {} copy failed error={}", part, err),
+    };
+}
+
+pub fn handle_key(state: &mut State, key: KeyEvent) -> Flow {
+    if key.kind != KeyEventKind::Press {
+        return Flow::Continue;
+    }
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return Flow::Quit,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Flow::Quit,
+        KeyCode::Up | KeyCode::Char('k') => state.previous_day(),
+        KeyCode::Down | KeyCode::Char('j') => state.next_day(),
+        KeyCode::Tab | KeyCode::Char('e') => state.next_source(),
+        KeyCode::Enter | KeyCode::Char('s') => state.solve(),
+        KeyCode::Char('r') => state.resolve(),
+        KeyCode::Char('1') => copy(state, 1),
+        KeyCode::Char('2') => copy(state, 2),
+        _ => {}
+    }
+
+    Flow::Continue
+}
@@ -0,0 +1,72 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod ui;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod event;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod state;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod terminal {
+    use anyhow::Result;
+    use crossterm::event::Event;
+    use crossterm::terminal::{
+        disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+    };
+    use crossterm::ExecutableCommand;
+    use ratatui::backend::CrosstermBackend;
+    use ratatui::Terminal;
+    use solutions::provider::Provider;
+    use std::io::{self, Stdout};
+    use std::time::Duration;
+
+    use crate::event::{self, Flow};
+    use crate::state::State;
+    use crate::ui;
+
+    struct Guard;
+
+    impl Guard {
+        fn enter() -> Result<Self> {
+            enable_raw_mode()?;
+            io::stdout().execute(EnterAlternateScreen)?;
+
+            Ok(Guard)
+        }
+    }
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let _result = io::stdout().execute(LeaveAlternateScreen);
+            let _result = disable_raw_mode();
+        }
+    }
+
+    fn event_loop(
+        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+        state: &mut State,
+    ) -> Result<()> {
+        loop {
+            terminal.draw(|frame| ui::draw(frame, state))?;
+
+            if let Event::Key(key) = crossterm::event::read()? {
+                if let Flow::Quit = event::handle_key(state, key) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    pub fn run(provider: Box<dyn Provider>, day: usize, timeout: Option<Duration>) -> Result<()> {
+        let mut state = State::new(provider, day, timeout);
+
+        let _guard = Guard::enter()?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+        event_loop(&mut terminal, &mut state)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use terminal::run;
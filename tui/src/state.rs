@@ -0,0 +1,171 @@
+use solutions::provider::Provider;
+use solutions::runner::{RunReport, Runner, SystemClock};
+use solutions::Example;
+use std::time::Duration;
+
+pub const MAX_DAY: usize = 25;
+
+pub struct State {
+    provider: Box<dyn Provider>,
+    timeout: Option<Duration>,
+    pub available: Vec<bool>,
+    pub day: usize,
+    pub examples: Vec<Example>,
+    pub source: usize,
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub report: Option<RunReport>,
+    pub error: Option<String>,
+    pub status: String,
+}
+
+impl State {
+    pub fn new(provider: Box<dyn Provider>, day: usize, timeout: Option<Duration>) -> Self {
+        let available = (1..=MAX_DAY)
+            .map(|day| provider.input(day).is_ok())
+            .collect();
+
+        let mut state = Self {
+            provider,
+            timeout,
+            available,
+            day: day.clamp(1, MAX_DAY),
+            examples: vec![],
+            source: 0,
+            input: String::new(),
+            part_1: None,
+            part_2: None,
+            report: None,
+            error: None,
+            status: String::new(),
+        };
+
+        state.handle_day_change();
+
+        state
+    }
+
+    pub fn example(&self) -> Option<&Example> {
+        self.source
+            .checked_sub(1)
+            .and_then(|index| self.examples.get(index))
+    }
+
+    pub fn source_name(&self) -> String {
+        match self.example() {
+            Some(example) => format!("example {}", example.name),
+            None => "input".to_string(),
+        }
+    }
+
+    fn clear_outputs(&mut self) {
+        self.part_1 = None;
+        self.part_2 = None;
+        self.report = None;
+        self.error = None;
+    }
+
+    pub fn reload(&mut self) {
+        self.clear_outputs();
+
+        match self.provider.examples(self.day) {
+            Ok(examples) => self.examples = examples,
+            Err(err) => {
+                self.examples = vec![];
+                self.error = Some(err.to_string());
+            }
+        }
+
+        if self.source > self.examples.len() {
+            self.source = 0;
+        }
+
+        if let Some(example) = self.example() {
+            self.input = example.input.to_string();
+            return;
+        }
+
+        match self.provider.input(self.day) {
+            Ok(input) => self.input = input,
+            Err(err) => {
+                self.input = String::new();
+                self.error = Some(err.to_string());
+            }
+        }
+    }
+
+    pub fn handle_day_change(&mut self) {
+        self.source = 0;
+        self.reload();
+        self.status = format!("day {} {}", self.day, self.source_name());
+    }
+
+    pub fn next_day(&mut self) {
+        if self.day < MAX_DAY {
+            self.day += 1;
+            self.handle_day_change();
+        }
+    }
+
+    pub fn previous_day(&mut self) {
+        if self.day > 1 {
+            self.day -= 1;
+            self.handle_day_change();
+        }
+    }
+
+    pub fn next_source(&mut self) {
+        self.source = (self.source + 1) % (self.examples.len() + 1);
+        self.reload();
+        self.status = format!("day {} {}", self.day, self.source_name());
+    }
+
+    pub fn solve(&mut self) {
+        self.clear_outputs();
+
+        let day = self.day;
+
+        let parts = self
+            .example()
+            .map(|example| example.parts)
+            .unwrap_or(solutions::Parts::Both);
+
+        let runner = Runner::new(SystemClock::default()).with_timeout(self.timeout);
+
+        let report = match runner.run(day, &self.input, parts) {
+            Ok(report) => report,
+            Err(err) => {
+                self.error = Some(err.to_string());
+                return;
+            }
+        };
+
+        self.part_1 = report.answer(1).map(str::to_string);
+        self.part_2 = report.answer(2).map(str::to_string);
+
+        match report.failure() {
+            Some(failure) => {
+                self.error = Some(format!(
+                    "day {} {} {} error={}",
+                    day,
+                    failure.phase.name(),
+                    failure.outcome.status(),
+                    failure.error().unwrap_or_default()
+                ));
+            }
+            None => self.status = format!("day {} {} solved", day, self.source_name()),
+        }
+
+        self.report = Some(report);
+    }
+
+    pub fn resolve(&mut self) {
+        self.reload();
+        self.solve();
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.example().and_then(|example| example.expected(part))
+    }
+}
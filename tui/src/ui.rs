@@ -0,0 +1,155 @@
+use crate::state::{State, MAX_DAY};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+use solutions::runner::{Phase, RunReport};
+use std::time::Duration;
+
+const HELP: &str = "↑/↓ day  tab source  enter solve  r re-solve  1/2 copy answer  q quit";
+
+fn days(frame: &mut Frame, state: &State, area: Rect) {
+    let items: Vec<ListItem> = (1..=MAX_DAY)
+        .map(|day| {
+            let style = if state.available[day - 1] {
+                Style::default()
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+
+            ListItem::new(format!("Day {:>2}", day)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Days"))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut list_state = ListState::default().with_selected(Some(state.day - 1));
+
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn input(frame: &mut Frame, state: &State, area: Rect) {
+    let title = format!(
+        "Input - {} ({} lines, {} bytes)",
+        state.source_name(),
+        state.input.lines().count(),
+        state.input.len()
+    );
+
+    let preview = Paragraph::new(state.input.as_str())
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    frame.render_widget(preview, area);
+}
+
+fn answer(state: &State, part: u8) -> Line<'static> {
+    let solution = match part {
+        1 => &state.part_1,
+        _ => &state.part_2,
+    };
+
+    let mut spans = vec![Span::raw(format!("P{}: ", part))];
+
+    let Some(solution) = solution else {
+        return Line::from(spans);
+    };
+
+    spans.push(Span::styled(
+        solution.clone(),
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+
+    match state.expected(part) {
+        Some(expected) if expected == solution => {
+            spans.push(Span::styled("  pass", Style::default().fg(Color::Green)));
+        }
+        Some(expected) => {
+            spans.push(Span::styled(
+                format!("  fail, expected {}", expected),
+                Style::default().fg(Color::Red),
+            ));
+        }
+        None => {}
+    }
+
+    Line::from(spans)
+}
+
+fn answers(frame: &mut Frame, state: &State, area: Rect) {
+    let answers = Paragraph::new(vec![answer(state, 1), answer(state, 2)])
+        .block(Block::default().borders(Borders::ALL).title("Answers"));
+
+    frame.render_widget(answers, area);
+}
+
+fn timing(label: &str, duration: Option<Duration>) -> Line<'static> {
+    match duration {
+        Some(duration) => Line::from(format!("{:<7}{:?}", label, duration)),
+        None => Line::from(format!("{:<7}-", label)),
+    }
+}
+
+fn timings(frame: &mut Frame, state: &State, area: Rect) {
+    let report = state.report.as_ref();
+    let duration = |phase| report.and_then(|report| report.duration(phase));
+
+    let timings = Paragraph::new(vec![
+        timing("parse", duration(Phase::Parse)),
+        timing("part 1", duration(Phase::Part1)),
+        timing("part 2", duration(Phase::Part2)),
+        timing("total", report.map(RunReport::total)),
+    ])
+    .block(Block::default().borders(Borders::ALL).title("Timings"));
+
+    frame.render_widget(timings, area);
+}
+
+fn error(frame: &mut Frame, state: &State, area: Rect) {
+    let (text, style) = match &state.error {
+        Some(error) => (error.as_str(), Style::default().fg(Color::Red)),
+        None => (state.status.as_str(), Style::default()),
+    };
+
+    let error = Paragraph::new(text)
+        .style(style)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("Status"));
+
+    frame.render_widget(error, area);
+}
+
+pub fn draw(frame: &mut Frame, state: &State) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(frame.size());
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(10), Constraint::Min(0)])
+        .split(rows[0]);
+
+    let panes = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(4),
+            Constraint::Length(6),
+            Constraint::Length(4),
+        ])
+        .split(columns[1]);
+
+    days(frame, state, columns[0]);
+    input(frame, state, panes[0]);
+    answers(frame, state, panes[1]);
+    timings(frame, state, panes[2]);
+    error(frame, state, panes[3]);
+
+    frame.render_widget(
+        Paragraph::new(HELP).style(Style::default().fg(Color::DarkGray)),
+        rows[1],
+    );
+}
@@ -0,0 +1,228 @@
+use solutions::grid::{Grid, Style};
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+const BACKGROUND: &str = "#24283b";
+const LABEL: &str = "#e0af68";
+
+// Below this many pixels per cell glyphs are unreadable, so cells are drawn as blocks.
+const GLYPH_SCALE: f64 = 8.0;
+const LABEL_SCALE: f64 = 16.0;
+
+const MIN_SCALE: f64 = 1.0;
+const MAX_SCALE: f64 = 64.0;
+
+fn color(style: Style) -> &'static str {
+    match style {
+        Style::Plain => "#a9b1d6",
+        Style::Dim => "#414868",
+        Style::Highlight => "#00cc00",
+        Style::Accent => "#ffff66",
+    }
+}
+
+#[derive(Default)]
+struct View {
+    grid: Option<Grid>,
+    scale: f64,
+    x: f64,
+    y: f64,
+    drag: Option<(f64, f64)>,
+}
+
+#[derive(Clone)]
+pub struct GridCanvas {
+    canvas: HtmlCanvasElement,
+    context: CanvasRenderingContext2d,
+    view: Rc<RefCell<View>>,
+}
+
+impl GridCanvas {
+    pub fn new(canvas: HtmlCanvasElement) -> Self {
+        let context = canvas
+            .get_context("2d")
+            .unwrap()
+            .unwrap()
+            .dyn_into::<CanvasRenderingContext2d>()
+            .unwrap();
+
+        Self {
+            canvas,
+            context,
+            view: Rc::new(RefCell::new(View::default())),
+        }
+    }
+
+    pub fn canvas(&self) -> &HtmlCanvasElement {
+        &self.canvas
+    }
+
+    fn size(&self) -> (f64, f64) {
+        (self.canvas.width() as f64, self.canvas.height() as f64)
+    }
+
+    pub fn show(&self, grid: Option<Grid>) {
+        self.view.borrow_mut().grid = grid;
+        self.fit();
+    }
+
+    // Keeps the current pan and zoom when the grid has the same size, as between frames.
+    pub fn replace(&self, grid: Grid) {
+        let same_size =
+            self.view.borrow().grid.as_ref().is_some_and(|current| {
+                current.width == grid.width && current.height == grid.height
+            });
+
+        if !same_size {
+            self.show(Some(grid));
+            return;
+        }
+
+        self.view.borrow_mut().grid = Some(grid);
+        self.draw();
+    }
+
+    // Scales the whole grid into the canvas and centers it.
+    pub fn fit(&self) {
+        let (width, height) = self.size();
+
+        let fitted = self.view.borrow().grid.as_ref().map(|grid| {
+            let columns = grid.width.max(1) as f64;
+            let rows = grid.height.max(1) as f64;
+            let scale = (width / columns)
+                .min(height / rows)
+                .clamp(MIN_SCALE, MAX_SCALE);
+
+            (
+                scale,
+                (width - columns * scale) / 2.0,
+                (height - rows * scale) / 2.0,
+            )
+        });
+
+        if let Some((scale, x, y)) = fitted {
+            let mut view = self.view.borrow_mut();
+
+            view.scale = scale;
+            view.x = x;
+            view.y = y;
+        }
+
+        self.draw();
+    }
+
+    pub fn start_drag(&self, x: f64, y: f64) {
+        self.view.borrow_mut().drag = Some((x, y));
+    }
+
+    pub fn drag_to(&self, x: f64, y: f64) {
+        {
+            let mut view = self.view.borrow_mut();
+
+            let Some((from_x, from_y)) = view.drag else {
+                return;
+            };
+
+            view.x += x - from_x;
+            view.y += y - from_y;
+            view.drag = Some((x, y));
+        }
+
+        self.draw();
+    }
+
+    pub fn end_drag(&self) {
+        self.view.borrow_mut().drag = None;
+    }
+
+    // Zooms around the canvas point (x, y), keeping the cell under it in place.
+    pub fn zoom(&self, factor: f64, x: f64, y: f64) {
+        {
+            let mut view = self.view.borrow_mut();
+
+            if view.grid.is_none() {
+                return;
+            }
+
+            let scale = (view.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+            let applied = scale / view.scale;
+
+            view.x = x - (x - view.x) * applied;
+            view.y = y - (y - view.y) * applied;
+            view.scale = scale;
+        }
+
+        self.draw();
+    }
+
+    pub fn draw(&self) {
+        let (width, height) = self.size();
+        let view = self.view.borrow();
+        let context = &self.context;
+
+        context.set_fill_style(&BACKGROUND.into());
+        context.fill_rect(0.0, 0.0, width, height);
+
+        let Some(grid) = &view.grid else {
+            return;
+        };
+
+        let scale = view.scale;
+
+        let first_x = ((-view.x / scale).floor().max(0.0)) as usize;
+        let first_y = ((-view.y / scale).floor().max(0.0)) as usize;
+        let last_x = (((width - view.x) / scale).ceil().max(0.0) as usize).min(grid.width);
+        let last_y = (((height - view.y) / scale).ceil().max(0.0) as usize).min(grid.height);
+
+        context.set_text_align("center");
+        context.set_text_baseline("middle");
+        context.set_font(&format!("{}px \"Source Code Pro\", monospace", scale * 0.8));
+
+        for y in first_y..last_y {
+            for x in first_x..last_x {
+                let Some(cell) = grid.cell(x, y) else {
+                    continue;
+                };
+
+                if cell.glyph == ' ' {
+                    continue;
+                }
+
+                let left = view.x + x as f64 * scale;
+                let top = view.y + y as f64 * scale;
+
+                context.set_fill_style(&color(cell.style).into());
+
+                if scale >= GLYPH_SCALE {
+                    let mut glyph = [0; 4];
+                    let _result = context.fill_text(
+                        cell.glyph.encode_utf8(&mut glyph),
+                        left + scale / 2.0,
+                        top + scale / 2.0,
+                    );
+                } else if cell.style != Style::Dim {
+                    context.fill_rect(left, top, scale, scale);
+                }
+            }
+        }
+
+        if scale < LABEL_SCALE {
+            return;
+        }
+
+        context.set_fill_style(&LABEL.into());
+        context.set_text_align("left");
+        context.set_text_baseline("top");
+        context.set_font(&format!("{}px \"Source Code Pro\", monospace", scale * 0.4));
+
+        for label in &grid.labels {
+            let _result = context.fill_text(
+                &label.text,
+                view.x + label.x as f64 * scale + 1.0,
+                view.y + label.y as f64 * scale + 1.0,
+            );
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::{anyhow, bail, Result};
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{HtmlDocument, HtmlTextAreaElement};
+
+// web-sys only exposes `navigator.clipboard` behind an unstable cfg, so it is looked up by name.
+async fn write_clipboard_api(text: &str) -> Result<()> {
+    let navigator = gloo_utils::window().navigator();
+
+    let clipboard = Reflect::get(&navigator, &JsValue::from_str("clipboard"))
+        .ok()
+        .filter(|clipboard| !clipboard.is_undefined())
+        .ok_or_else(|| anyhow!("clipboard API is unavailable"))?;
+
+    let write_text = Reflect::get(&clipboard, &JsValue::from_str("writeText"))
+        .ok()
+        .and_then(|write_text| write_text.dyn_into::<Function>().ok())
+        .ok_or_else(|| anyhow!("clipboard API is unavailable"))?;
+
+    let promise = write_text
+        .call1(&clipboard, &JsValue::from_str(text))
+        .ok()
+        .and_then(|promise| promise.dyn_into::<Promise>().ok())
+        .ok_or_else(|| anyhow!("clipboard write failed"))?;
+
+    JsFuture::from(promise)
+        .await
+        .map_err(|_| anyhow!("clipboard write was denied"))?;
+
+    Ok(())
+}
+
+fn write_exec_command(text: &str) -> Result<()> {
+    let document = gloo_utils::document();
+
+    let Some(body) = document.body() else {
+        bail!("no document body to copy from")
+    };
+
+    let Ok(textarea) = document
+        .create_element("textarea")
+        .map(|element| element.unchecked_into::<HtmlTextAreaElement>())
+    else {
+        bail!("unable to create a copy selection")
+    };
+
+    textarea.set_value(text);
+    let _result = textarea.set_attribute("readonly", "");
+    let _result = textarea.set_attribute("style", "position: fixed; top: 0; opacity: 0;");
+    let _result = body.append_child(&textarea);
+
+    textarea.select();
+
+    let copied = document
+        .dyn_into::<HtmlDocument>()
+        .ok()
+        .and_then(|document| document.exec_command("copy").ok())
+        .unwrap_or(false);
+
+    textarea.remove();
+
+    if !copied {
+        bail!("the browser refused to copy")
+    }
+
+    Ok(())
+}
+
+pub async fn write(text: &str) -> Result<()> {
+    if write_clipboard_api(text).await.is_ok() {
+        return Ok(());
+    }
+
+    write_exec_command(text)
+}
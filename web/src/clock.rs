@@ -0,0 +1,28 @@
+use js_sys::Reflect;
+use solutions::runner::Clock;
+use std::time::Duration;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::Performance;
+
+pub struct PerformanceClock {
+    performance: Performance,
+}
+
+// Read from the global scope rather than the window, so the clock also works inside the solver
+// worker.
+impl Default for PerformanceClock {
+    fn default() -> Self {
+        let performance = Reflect::get(&js_sys::global(), &JsValue::from_str("performance"))
+            .expect("global scope has no performance");
+
+        Self {
+            performance: performance.unchecked_into(),
+        }
+    }
+}
+
+impl Clock for PerformanceClock {
+    fn now(&self) -> Duration {
+        Duration::from_secs_f64(self.performance.now() / 1000.0)
+    }
+}
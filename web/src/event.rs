@@ -1,111 +1,520 @@
+use crate::clipboard;
+use crate::file;
+use crate::message::Request;
 use crate::ui::UIRef;
-use gloo_events::EventListener;
-use solutions::solvers::Solvers;
+use crate::worker::SolverWorker;
+use gloo_events::{EventListener, EventListenerOptions};
+use gloo_timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{
+    DragEvent, File, HtmlInputElement, HtmlTextAreaElement, KeyboardEvent, MouseEvent, WheelEvent,
+};
+
+const LIVE_DELAY_MS: u32 = 300;
+
+const ZOOM_STEP: f64 = 1.2;
+
+type Debounce = Rc<RefCell<Option<Timeout>>>;
 
 pub struct EventProcessor {
     ui_ref: UIRef,
+    worker: SolverWorker,
+    live: Debounce,
+    location: Debounce,
 }
 
 impl EventProcessor {
     pub fn new(ui_ref: UIRef) -> Self {
-        Self { ui_ref }
+        let worker = SolverWorker::new(ui_ref.clone());
+
+        Self {
+            ui_ref,
+            worker,
+            live: Rc::new(RefCell::new(None)),
+            location: Rc::new(RefCell::new(None)),
+        }
     }
 
-    #[cfg(web_sys_unstable_apis)]
-    fn part_1_click_handler(ui_ref: &UIRef) {
-        let solution = ui_ref.part_1();
+    fn copy(ui_ref: &UIRef, part: u8, solution: String) {
+        let mut ui_clone = ui_ref.clone();
 
-        if let Some(clipboard) = web_sys::window().unwrap().navigator().clipboard() {
-            let _promise = clipboard.write_text(&solution);
-        };
+        if solution.is_empty() {
+            ui_clone.flash_status(&format!("Part {} has no answer to copy", part));
+            return;
+        }
+
+        wasm_bindgen_futures::spawn_local(async move {
+            match clipboard::write(&solution).await {
+                Ok(()) => ui_clone.flash_status(&format!("Part {} copied", part)),
+                Err(error) => {
+                    let day = ui_clone.day();
+                    ui_clone.fail_status(day, &format!("part {} copy failed", part), error);
+                }
+            }
+        });
+    }
+
+    fn part_1_click_handler(ui_ref: &UIRef) {
+        EventProcessor::copy(ui_ref, 1, ui_ref.part_1());
     }
 
-    #[cfg(web_sys_unstable_apis)]
     fn part_2_click_handler(ui_ref: &UIRef) {
-        let solution = ui_ref.part_2();
+        EventProcessor::copy(ui_ref, 2, ui_ref.part_2());
+    }
 
-        if let Some(clipboard) = web_sys::window().unwrap().navigator().clipboard() {
-            let _promise = clipboard.write_text(&solution);
-        };
+    fn cancel(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        if let Some(day) = worker.cancel() {
+            ui_ref.set_running(false);
+            ui_ref.set_status(&format!("Day {} cancelled", day));
+        }
     }
 
-    fn next_click_handler(ui_ref: &mut UIRef) {
+    fn next_click_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
         ui_ref.next_day();
     }
 
-    fn previous_click_handler(ui_ref: &mut UIRef) {
+    fn previous_click_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
         ui_ref.previous_day();
     }
 
-    fn day_select_handler(ui_ref: &mut UIRef) {
+    fn day_select_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
         ui_ref.handle_day_change();
     }
 
-    fn solve_handler(ui_ref: &mut UIRef) {
-        ui_ref.clear_outputs();
+    fn source_select_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
+        ui_ref.handle_source_change();
+    }
 
-        let input = ui_ref.input();
-        let day: usize = ui_ref.day();
+    fn parts_select_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
+        ui_ref.handle_parts_change();
+    }
 
-        let Some(mut solver) = Solvers::get(day) else {
-            return;
-        };
+    fn input_handler(
+        ui_ref: &mut UIRef,
+        worker: &SolverWorker,
+        live: &Debounce,
+        location: &Debounce,
+    ) {
+        ui_ref.handle_input_edit();
+        EventProcessor::schedule_location(ui_ref, location);
+
+        if ui_ref.live() {
+            EventProcessor::schedule_live(ui_ref, worker, live);
+        }
+    }
+
+    // Compressing a large custom input into the link is too slow to repeat on every keystroke.
+    fn schedule_location(ui_ref: &UIRef, location: &Debounce) {
+        let ui_clone = ui_ref.clone();
 
-        solver.setup(&input);
+        let timeout = Timeout::new(LIVE_DELAY_MS, move || {
+            ui_clone.replace_custom_location();
+        });
 
-        let parse_result =
-            gloo_console::Timer::scope(&format!("day {} parse", day), || solver.parse());
+        location.borrow_mut().replace(timeout);
+    }
 
-        if let Err(error) = parse_result {
-            ui_ref.fail_status(day, "parse", error);
-            return;
+    // Replacing the pending timeout clears it, so a live solve only starts once typing pauses.
+    fn schedule_live(ui_ref: &UIRef, worker: &SolverWorker, live: &Debounce) {
+        let mut ui_clone = ui_ref.clone();
+        let worker = worker.clone();
+
+        let timeout = Timeout::new(LIVE_DELAY_MS, move || {
+            EventProcessor::cancel(&mut ui_clone, &worker);
+            EventProcessor::solve_handler(&mut ui_clone, &worker);
+        });
+
+        live.borrow_mut().replace(timeout);
+    }
+
+    fn live_toggle_handler(ui_ref: &mut UIRef, worker: &SolverWorker, live: &Debounce) {
+        if ui_ref.live() {
+            EventProcessor::schedule_live(ui_ref, worker, live);
+        } else {
+            live.borrow_mut().take();
         }
+    }
 
-        let part_1_result =
-            gloo_console::Timer::scope(&format!("day {} solve 1", day), || solver.part_1());
+    fn load_file(ui_ref: &mut UIRef, worker: &SolverWorker, live: &Debounce, file: File) {
+        EventProcessor::cancel(ui_ref, worker);
 
-        let part_1 = match part_1_result {
-            Ok(result) => result,
-            Err(err) => {
-                ui_ref.fail_status(day, "solve part 1", err);
-                return;
+        let mut ui_clone = ui_ref.clone();
+        let worker = worker.clone();
+        let live = live.clone();
+        let name = file.name();
+        let size = file.size();
+
+        file::read(&file, move |result| {
+            ui_clone.load_file(&name, size, result);
+
+            if ui_clone.live() {
+                EventProcessor::schedule_live(&ui_clone, &worker, &live);
             }
+        });
+    }
+
+    fn file_picked_handler(ui_ref: &mut UIRef, worker: &SolverWorker, live: &Debounce) {
+        if let Some(file) = ui_ref.picked_file() {
+            EventProcessor::load_file(ui_ref, worker, live, file);
+        }
+    }
+
+    // Dropped text is left to the textarea, only files are taken over.
+    fn drop_handler(ui_ref: &mut UIRef, worker: &SolverWorker, live: &Debounce, event: &DragEvent) {
+        ui_ref.set_dragging(false);
+
+        let file = event
+            .data_transfer()
+            .and_then(|transfer| transfer.files())
+            .and_then(|files| files.get(0));
+
+        if let Some(file) = file {
+            event.prevent_default();
+            EventProcessor::load_file(ui_ref, worker, live, file);
+        }
+    }
+
+    fn visualize_toggle_handler(ui_ref: &mut UIRef) {
+        ui_ref.set_visualizing(ui_ref.visualize());
+    }
+
+    fn wheel_handler(ui_ref: &UIRef, event: &WheelEvent) {
+        let factor = if event.delta_y() < 0.0 {
+            ZOOM_STEP
+        } else {
+            1.0 / ZOOM_STEP
         };
 
-        let part_2_result =
-            gloo_console::Timer::scope(&format!("day {} solve 2", day), || solver.part_2());
+        ui_ref
+            .grid()
+            .zoom(factor, event.offset_x() as f64, event.offset_y() as f64);
+    }
+
+    fn typing(event: &KeyboardEvent) -> bool {
+        event.target().is_some_and(|target| {
+            target.has_type::<HtmlTextAreaElement>() || target.has_type::<HtmlInputElement>()
+        })
+    }
 
-        let part_2 = match part_2_result {
-            Ok(result) => result,
-            Err(err) => {
-                ui_ref.fail_status(day, "solve part 2", err);
-                return;
+    fn keydown_handler(ui_ref: &mut UIRef, worker: &SolverWorker, event: &KeyboardEvent) {
+        let alt = event.alt_key();
+        let ctrl = event.ctrl_key() || event.meta_key();
+
+        match event.code().as_str() {
+            "Enter" | "NumpadEnter" if ctrl => EventProcessor::solve_handler(ui_ref, worker),
+            "ArrowLeft" if alt => EventProcessor::previous_click_handler(ui_ref, worker),
+            "ArrowRight" if alt => EventProcessor::next_click_handler(ui_ref, worker),
+            "Digit1" | "Numpad1" if alt => EventProcessor::part_1_click_handler(ui_ref),
+            "Digit2" | "Numpad2" if alt => EventProcessor::part_2_click_handler(ui_ref),
+            "Escape" if ui_ref.help_open() => ui_ref.show_help(false),
+            _ if event.key() == "?" && !EventProcessor::typing(event) => {
+                ui_ref.show_help(!ui_ref.help_open())
             }
+            _ => return,
+        }
+
+        event.prevent_default();
+    }
+
+    fn reset_click_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
+        ui_ref.reset_input();
+    }
+
+    fn cancel_click_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        EventProcessor::cancel(ui_ref, worker);
+    }
+
+    fn solve_handler(ui_ref: &mut UIRef, worker: &SolverWorker) {
+        if worker.running().is_some() {
+            return;
+        }
+
+        let day = ui_ref.day();
+
+        let Some(parts) = ui_ref.parts() else {
+            ui_ref.fail_status(
+                day,
+                "solve",
+                "the example does not apply to the selected part",
+            );
+            return;
         };
 
-        ui_ref.set_part_1(&part_1);
-        ui_ref.set_part_2(&part_2);
+        ui_ref.clear_outputs();
+        ui_ref.set_running(true);
+
+        ui_ref.set_status(&format!("Day {} solving", day));
+
+        worker.solve(Request {
+            day,
+            parts,
+            input: ui_ref.input(),
+            grid: ui_ref.visualize(),
+        });
+    }
+
+    fn register_grid(&mut self) {
+        let mut ui_clone = self.ui_ref.clone();
+
+        let _visualize_listener = EventListener::new(
+            self.ui_ref.visualize_event_target(),
+            "change",
+            move |_event| {
+                EventProcessor::visualize_toggle_handler(&mut ui_clone);
+            },
+        );
+
+        let grid = self.ui_ref.grid().clone();
+
+        let _mousedown_listener =
+            EventListener::new(self.ui_ref.grid_event_target(), "mousedown", move |event| {
+                if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                    grid.start_drag(event.offset_x() as f64, event.offset_y() as f64);
+                }
+            });
+
+        let grid = self.ui_ref.grid().clone();
+
+        let _mousemove_listener =
+            EventListener::new(self.ui_ref.grid_event_target(), "mousemove", move |event| {
+                if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                    grid.drag_to(event.offset_x() as f64, event.offset_y() as f64);
+                }
+            });
+
+        let grid = self.ui_ref.grid().clone();
+
+        let _mouseup_listener =
+            EventListener::new(self.ui_ref.grid_event_target(), "mouseup", move |_event| {
+                grid.end_drag();
+            });
+
+        let grid = self.ui_ref.grid().clone();
+
+        let _mouseleave_listener = EventListener::new(
+            self.ui_ref.grid_event_target(),
+            "mouseleave",
+            move |_event| {
+                grid.end_drag();
+            },
+        );
+
+        let grid = self.ui_ref.grid().clone();
+
+        let _dblclick_listener =
+            EventListener::new(self.ui_ref.grid_event_target(), "dblclick", move |_event| {
+                grid.fit();
+            });
+
+        let ui_clone = self.ui_ref.clone();
+
+        let _wheel_listener = EventListener::new_with_options(
+            self.ui_ref.grid_event_target(),
+            "wheel",
+            EventListenerOptions::enable_prevent_default(),
+            move |event| {
+                if let Some(event) = event.dyn_ref::<WheelEvent>() {
+                    event.prevent_default();
+                    EventProcessor::wheel_handler(&ui_clone, event);
+                }
+            },
+        );
+
+        let ui_clone = self.ui_ref.clone();
+
+        let _play_listener =
+            EventListener::new(self.ui_ref.play_event_target(), "click", move |_event| {
+                ui_clone.player().toggle();
+            });
+
+        let ui_clone = self.ui_ref.clone();
+
+        let _step_back_listener = EventListener::new(
+            self.ui_ref.step_back_event_target(),
+            "click",
+            move |_event| {
+                ui_clone.player().pause();
+                ui_clone.player().step(-1);
+            },
+        );
+
+        let ui_clone = self.ui_ref.clone();
+
+        let _step_forward_listener = EventListener::new(
+            self.ui_ref.step_forward_event_target(),
+            "click",
+            move |_event| {
+                ui_clone.player().pause();
+                ui_clone.player().step(1);
+            },
+        );
+
+        _visualize_listener.forget();
+        _play_listener.forget();
+        _step_back_listener.forget();
+        _step_forward_listener.forget();
+        _mousedown_listener.forget();
+        _mousemove_listener.forget();
+        _mouseup_listener.forget();
+        _mouseleave_listener.forget();
+        _dblclick_listener.forget();
+        _wheel_listener.forget();
     }
 
     pub fn register(&mut self) {
+        self.register_grid();
+
         let solve_event_target = self.ui_ref.solve_event_target();
 
         let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
 
         let _solve_listener = EventListener::new(&solve_event_target, "click", move |_event| {
-            EventProcessor::solve_handler(&mut ui_clone);
+            EventProcessor::solve_handler(&mut ui_clone, &worker);
         });
 
         let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
 
         let _day_listener = EventListener::new(
             self.ui_ref.day_select_event_target(),
             "change",
             move |_event| {
-                EventProcessor::day_select_handler(&mut ui_clone);
+                EventProcessor::day_select_handler(&mut ui_clone, &worker);
             },
         );
 
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+
+        let _source_listener =
+            EventListener::new(self.ui_ref.source_event_target(), "change", move |_event| {
+                EventProcessor::source_select_handler(&mut ui_clone, &worker);
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+
+        let _parts_listener =
+            EventListener::new(self.ui_ref.parts_event_target(), "change", move |_event| {
+                EventProcessor::parts_select_handler(&mut ui_clone, &worker);
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+        let live = self.live.clone();
+        let location = self.location.clone();
+
+        let _input_listener =
+            EventListener::new(self.ui_ref.input_event_target(), "input", move |_event| {
+                EventProcessor::input_handler(&mut ui_clone, &worker, &live, &location);
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+        let live = self.live.clone();
+
+        let _live_listener =
+            EventListener::new(self.ui_ref.live_event_target(), "change", move |_event| {
+                EventProcessor::live_toggle_handler(&mut ui_clone, &worker, &live);
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+
+        let _keydown_listener =
+            EventListener::new(&gloo_utils::document(), "keydown", move |event| {
+                if let Some(event) = event.dyn_ref::<KeyboardEvent>() {
+                    EventProcessor::keydown_handler(&mut ui_clone, &worker, event);
+                }
+            });
+
+        let ui_clone = self.ui_ref.clone();
+
+        let _open_listener =
+            EventListener::new(self.ui_ref.open_event_target(), "click", move |_event| {
+                ui_clone.open_file_picker();
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+        let live = self.live.clone();
+
+        let _file_listener =
+            EventListener::new(self.ui_ref.file_event_target(), "change", move |_event| {
+                EventProcessor::file_picked_handler(&mut ui_clone, &worker, &live);
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+
+        let _dragover_listener = EventListener::new_with_options(
+            self.ui_ref.input_event_target(),
+            "dragover",
+            EventListenerOptions::enable_prevent_default(),
+            move |event| {
+                event.prevent_default();
+                ui_clone.set_dragging(true);
+            },
+        );
+
+        let mut ui_clone = self.ui_ref.clone();
+
+        let _dragleave_listener = EventListener::new(
+            self.ui_ref.input_event_target(),
+            "dragleave",
+            move |_event| {
+                ui_clone.set_dragging(false);
+            },
+        );
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+        let live = self.live.clone();
+
+        let _drop_listener = EventListener::new_with_options(
+            self.ui_ref.input_event_target(),
+            "drop",
+            EventListenerOptions::enable_prevent_default(),
+            move |event| {
+                if let Some(event) = event.dyn_ref::<DragEvent>() {
+                    EventProcessor::drop_handler(&mut ui_clone, &worker, &live, event);
+                }
+            },
+        );
+
+        let mut ui_clone = self.ui_ref.clone();
+
+        let _help_button_listener = EventListener::new(
+            self.ui_ref.help_button_event_target(),
+            "click",
+            move |_event| {
+                ui_clone.show_help(true);
+            },
+        );
+
+        let mut ui_clone = self.ui_ref.clone();
+
+        let _help_listener =
+            EventListener::new(self.ui_ref.help_event_target(), "click", move |_event| {
+                ui_clone.show_help(false);
+            });
+
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+
+        let _reset_listener =
+            EventListener::new(self.ui_ref.reset_event_target(), "click", move |_event| {
+                EventProcessor::reset_click_handler(&mut ui_clone, &worker);
+            });
+
         let ui_clone = self.ui_ref.clone();
 
         let _part_1_copy_listener = EventListener::new(
@@ -127,27 +536,51 @@ impl EventProcessor {
         );
 
         let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
 
         let _next_listener =
             EventListener::new(self.ui_ref.next_event_target(), "click", move |_event| {
-                EventProcessor::next_click_handler(&mut ui_clone);
+                EventProcessor::next_click_handler(&mut ui_clone, &worker);
             });
 
         let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
 
         let _previous_listener = EventListener::new(
             self.ui_ref.previous_event_target(),
             "click",
             move |_event| {
-                EventProcessor::previous_click_handler(&mut ui_clone);
+                EventProcessor::previous_click_handler(&mut ui_clone, &worker);
             },
         );
 
+        let mut ui_clone = self.ui_ref.clone();
+        let worker = self.worker.clone();
+
+        let _cancel_listener =
+            EventListener::new(self.ui_ref.cancel_event_target(), "click", move |_event| {
+                EventProcessor::cancel_click_handler(&mut ui_clone, &worker);
+            });
+
         _part_2_copy_listener.forget();
         _part_1_copy_listener.forget();
         _day_listener.forget();
+        _source_listener.forget();
+        _parts_listener.forget();
+        _input_listener.forget();
+        _live_listener.forget();
+        _keydown_listener.forget();
+        _help_button_listener.forget();
+        _help_listener.forget();
+        _open_listener.forget();
+        _file_listener.forget();
+        _dragover_listener.forget();
+        _dragleave_listener.forget();
+        _drop_listener.forget();
+        _reset_listener.forget();
         _solve_listener.forget();
         _next_listener.forget();
         _previous_listener.forget();
+        _cancel_listener.forget();
     }
 }
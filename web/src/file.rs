@@ -0,0 +1,55 @@
+use crate::storage::MAX_SIZE;
+use anyhow::{anyhow, bail, Result};
+use gloo_events::EventListener;
+use js_sys::{ArrayBuffer, Uint8Array};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{File, FileReader};
+
+pub fn format_size(bytes: f64) -> String {
+    if bytes < 1024.0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} KiB", bytes / 1024.0)
+    }
+}
+
+fn decode(bytes: Vec<u8>) -> Result<String> {
+    match String::from_utf8(bytes) {
+        Ok(text) if !text.contains('\0') => Ok(text),
+        _ => bail!("not a text file"),
+    }
+}
+
+// Reads a dropped or picked file, calling `done` with its text or the reason it was rejected.
+pub fn read(file: &File, done: impl FnOnce(Result<String>) + 'static) {
+    if file.size() > MAX_SIZE as f64 {
+        done(Err(anyhow!(
+            "file is too large ({}, limit {})",
+            format_size(file.size()),
+            format_size(MAX_SIZE as f64)
+        )));
+        return;
+    }
+
+    let Ok(reader) = FileReader::new() else {
+        done(Err(anyhow!("file reading is unavailable")));
+        return;
+    };
+
+    let loaded = reader.clone();
+
+    let _listener = EventListener::once(&reader, "loadend", move |_event| {
+        let result = match loaded.result() {
+            Ok(buffer) if buffer.has_type::<ArrayBuffer>() => {
+                decode(Uint8Array::new(&buffer).to_vec())
+            }
+            _ => Err(anyhow!("unable to read file")),
+        };
+
+        done(result);
+    });
+
+    let _result = reader.read_as_array_buffer(file);
+
+    _listener.forget();
+}
@@ -0,0 +1,54 @@
+use solutions::runner::{Phase, RunReport};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+pub const HISTORY_SIZE: usize = 5;
+
+#[derive(Default)]
+pub struct History {
+    runs: HashMap<usize, VecDeque<RunReport>>,
+}
+
+impl History {
+    pub fn push(&mut self, report: RunReport) {
+        let runs = self.runs.entry(report.day).or_default();
+
+        runs.push_front(report);
+        runs.truncate(HISTORY_SIZE);
+    }
+
+    pub fn runs(&self, day: usize) -> impl Iterator<Item = &RunReport> {
+        self.runs.get(&day).into_iter().flatten()
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_secs_f64() * 1000.0;
+
+    if millis < 1000.0 {
+        format!("{:.2}ms", millis)
+    } else {
+        format!("{:.2}s", millis / 1000.0)
+    }
+}
+
+pub fn summary(report: &RunReport) -> String {
+    let phases: Vec<String> = [
+        (Phase::Parse, "parse"),
+        (Phase::Part1, "P1"),
+        (Phase::Part2, "P2"),
+    ]
+    .into_iter()
+    .filter_map(|(phase, label)| {
+        report
+            .duration(phase)
+            .map(|duration| format!("{} {}", label, format_duration(duration)))
+    })
+    .collect();
+
+    format!(
+        "{} ({})",
+        format_duration(report.total()),
+        phases.join(", ")
+    )
+}
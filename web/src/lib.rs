@@ -1,27 +1,70 @@
+#[cfg(target_arch = "wasm32")]
+mod canvas;
+
+#[cfg(target_arch = "wasm32")]
+mod clipboard;
+
+#[cfg(target_arch = "wasm32")]
+mod clock;
+
 #[cfg(target_arch = "wasm32")]
 mod ui;
 
 #[cfg(target_arch = "wasm32")]
 mod event;
 
+#[cfg(target_arch = "wasm32")]
+mod file;
+
+#[cfg(target_arch = "wasm32")]
+mod history;
+
+#[cfg(target_arch = "wasm32")]
+mod message;
+
+#[cfg(target_arch = "wasm32")]
+mod panic;
+
+#[cfg(target_arch = "wasm32")]
+mod player;
+
 #[cfg(target_arch = "wasm32")]
 mod state;
 
+#[cfg(target_arch = "wasm32")]
+mod storage;
+
+#[cfg(target_arch = "wasm32")]
+mod worker;
+
 #[cfg(target_arch = "wasm32")]
 mod wasm {
     use ui::UIRef;
 
     use crate::event::EventProcessor;
-    use crate::state::State;
+    use crate::panic;
+    use crate::state::{Source, State};
     use crate::ui;
 
     pub async fn bind() {
         let state = State::from_location(&gloo_utils::window().location()).unwrap_or_default();
 
+        let source = state.source.clone();
+        let input = state.input.clone();
+
         let mut ui_ref = UIRef::new(state);
 
+        panic::install(ui_ref.clone());
+
         ui_ref.handle_day_change();
 
+        ui_ref.select_source(&source);
+
+        if let Some(input) = input.filter(|_| source == Source::Custom) {
+            ui_ref.set_input(&input);
+            ui_ref.handle_input_edit();
+        }
+
         let mut event_processor = EventProcessor::new(ui_ref);
 
         event_processor.register();
@@ -30,3 +73,6 @@ mod wasm {
 
 #[cfg(target_arch = "wasm32")]
 pub use wasm::bind;
+
+#[cfg(target_arch = "wasm32")]
+pub use worker::serve;
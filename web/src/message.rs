@@ -0,0 +1,326 @@
+use crate::state::parts_value;
+use js_sys::{Array, Object, Reflect};
+use solutions::grid::{Cell, Grid, Label, Style};
+use solutions::runner::{Outcome, Panic, Phase, PhaseReport, RunReport};
+use solutions::visualize::Frame;
+use solutions::Parts;
+use std::time::Duration;
+use web_sys::wasm_bindgen::JsValue;
+
+fn set(object: &Object, key: &str, value: impl Into<JsValue>) {
+    let _result = Reflect::set(object, &JsValue::from_str(key), &value.into());
+}
+
+fn get(value: &JsValue, key: &str) -> Option<JsValue> {
+    Reflect::get(value, &JsValue::from_str(key))
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+}
+
+fn get_string(value: &JsValue, key: &str) -> Option<String> {
+    get(value, key)?.as_string()
+}
+
+fn get_f64(value: &JsValue, key: &str) -> Option<f64> {
+    get(value, key)?.as_f64()
+}
+
+fn get_day(value: &JsValue) -> Option<usize> {
+    get_f64(value, "day").map(|day| day as usize)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn from_millis(millis: f64) -> Duration {
+    Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+}
+
+fn phase_name(phase: Phase) -> &'static str {
+    match phase {
+        Phase::Parse => "parse",
+        Phase::Part1 => "part_1",
+        Phase::Part2 => "part_2",
+    }
+}
+
+fn phase_from_name(name: &str) -> Option<Phase> {
+    match name {
+        "parse" => Some(Phase::Parse),
+        "part_1" => Some(Phase::Part1),
+        "part_2" => Some(Phase::Part2),
+        _ => None,
+    }
+}
+
+pub struct Request {
+    pub day: usize,
+    pub parts: Parts,
+    pub input: String,
+    pub grid: bool,
+}
+
+impl Request {
+    pub fn to_js(&self) -> JsValue {
+        let object = Object::new();
+
+        set(&object, "day", self.day as f64);
+        set(&object, "parts", parts_value(self.parts));
+        set(&object, "input", self.input.as_str());
+        set(&object, "grid", self.grid);
+
+        object.into()
+    }
+
+    pub fn from_js(value: &JsValue) -> Option<Self> {
+        Some(Self {
+            day: get_day(value)?,
+            parts: get_string(value, "parts")?.parse().ok()?,
+            input: get_string(value, "input")?,
+            grid: get(value, "grid").and_then(|grid| grid.as_bool()) == Some(true),
+        })
+    }
+}
+
+pub enum Response {
+    Ready,
+    Report(RunReport),
+    Error { day: usize, message: String },
+    Panic { day: usize, panic: Panic },
+    Grid { day: usize, grid: Option<Grid> },
+    Frames { day: usize, frames: Vec<Frame> },
+}
+
+fn style_code(style: Style) -> char {
+    match style {
+        Style::Plain => 'p',
+        Style::Dim => 'd',
+        Style::Highlight => 'h',
+        Style::Accent => 'a',
+    }
+}
+
+fn style_from_code(code: char) -> Style {
+    match code {
+        'd' => Style::Dim,
+        'h' => Style::Highlight,
+        'a' => Style::Accent,
+        _ => Style::Plain,
+    }
+}
+
+// Cells travel as two strings, one char per cell, rather than as an object per cell.
+fn grid_to_js(grid: &Grid) -> JsValue {
+    let object = Object::new();
+
+    let glyphs: String = grid.cells.iter().map(|cell| cell.glyph).collect();
+    let styles: String = grid
+        .cells
+        .iter()
+        .map(|cell| style_code(cell.style))
+        .collect();
+
+    let labels: Array = grid
+        .labels
+        .iter()
+        .map(|label| {
+            let object = Object::new();
+
+            set(&object, "x", label.x as f64);
+            set(&object, "y", label.y as f64);
+            set(&object, "text", label.text.as_str());
+
+            JsValue::from(object)
+        })
+        .collect();
+
+    set(&object, "width", grid.width as f64);
+    set(&object, "height", grid.height as f64);
+    set(&object, "glyphs", glyphs);
+    set(&object, "styles", styles);
+    set(&object, "labels", labels);
+
+    object.into()
+}
+
+fn frame_to_js(frame: &Frame) -> JsValue {
+    let object = Object::new();
+
+    match frame {
+        Frame::Grid(grid) => set(&object, "grid", grid_to_js(grid)),
+        Frame::Text(text) => set(&object, "text", text.as_str()),
+    }
+
+    object.into()
+}
+
+fn frame_from_js(value: &JsValue) -> Option<Frame> {
+    match get(value, "grid") {
+        Some(grid) => grid_from_js(&grid).map(Frame::Grid),
+        None => get_string(value, "text").map(Frame::Text),
+    }
+}
+
+fn grid_from_js(value: &JsValue) -> Option<Grid> {
+    let cells: Vec<Cell> = get_string(value, "glyphs")?
+        .chars()
+        .zip(get_string(value, "styles")?.chars())
+        .map(|(glyph, style)| Cell {
+            glyph,
+            style: style_from_code(style),
+        })
+        .collect();
+
+    let labels = Array::from(&get(value, "labels")?)
+        .iter()
+        .map(|label| {
+            Some(Label {
+                x: get_f64(&label, "x")? as usize,
+                y: get_f64(&label, "y")? as usize,
+                text: get_string(&label, "text")?,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let grid = Grid {
+        width: get_f64(value, "width")? as usize,
+        height: get_f64(value, "height")? as usize,
+        cells,
+        labels,
+    };
+
+    (grid.cells.len() == grid.width * grid.height).then_some(grid)
+}
+
+fn phase_to_js(report: &PhaseReport) -> JsValue {
+    let object = Object::new();
+
+    set(&object, "phase", phase_name(report.phase));
+    set(&object, "duration", millis(report.duration));
+    set(&object, "status", report.outcome.status());
+
+    match &report.outcome {
+        Outcome::Done(Some(answer)) => set(&object, "answer", answer.as_str()),
+        Outcome::Done(None) => {}
+        Outcome::Failed(error) => set(&object, "error", error.as_str()),
+        Outcome::Panicked(panic) => {
+            set(&object, "message", panic.message.as_str());
+
+            if let Some(location) = &panic.location {
+                set(&object, "location", location.as_str());
+            }
+        }
+        Outcome::TimedOut(timeout) => set(&object, "timeout", millis(*timeout)),
+    }
+
+    object.into()
+}
+
+fn panic_from_js(value: &JsValue) -> Panic {
+    Panic {
+        message: get_string(value, "message").unwrap_or_default(),
+        location: get_string(value, "location"),
+    }
+}
+
+fn phase_from_js(value: &JsValue) -> Option<PhaseReport> {
+    let outcome = match get_string(value, "status")?.as_str() {
+        "solved" => Outcome::Done(get_string(value, "answer")),
+        "failed" => Outcome::Failed(get_string(value, "error").unwrap_or_default()),
+        "panicked" => Outcome::Panicked(panic_from_js(value)),
+        "timed out" => Outcome::TimedOut(from_millis(get_f64(value, "timeout")?)),
+        _ => return None,
+    };
+
+    Some(PhaseReport {
+        phase: phase_from_name(&get_string(value, "phase")?)?,
+        duration: from_millis(get_f64(value, "duration")?),
+        outcome,
+    })
+}
+
+impl Response {
+    pub fn to_js(&self) -> JsValue {
+        let object = Object::new();
+
+        match self {
+            Response::Ready => set(&object, "kind", "ready"),
+            Response::Report(report) => {
+                let phases: Array = report.phases.iter().map(phase_to_js).collect();
+
+                set(&object, "kind", "report");
+                set(&object, "day", report.day as f64);
+                set(&object, "phases", phases);
+            }
+            Response::Error { day, message } => {
+                set(&object, "kind", "error");
+                set(&object, "day", *day as f64);
+                set(&object, "message", message.as_str());
+            }
+            Response::Panic { day, panic } => {
+                set(&object, "kind", "panic");
+                set(&object, "day", *day as f64);
+                set(&object, "message", panic.message.as_str());
+
+                if let Some(location) = &panic.location {
+                    set(&object, "location", location.as_str());
+                }
+            }
+            Response::Grid { day, grid } => {
+                set(&object, "kind", "grid");
+                set(&object, "day", *day as f64);
+
+                if let Some(grid) = grid {
+                    set(&object, "grid", grid_to_js(grid));
+                }
+            }
+            Response::Frames { day, frames } => {
+                let frames: Array = frames.iter().map(frame_to_js).collect();
+
+                set(&object, "kind", "frames");
+                set(&object, "day", *day as f64);
+                set(&object, "frames", frames);
+            }
+        }
+
+        object.into()
+    }
+
+    pub fn from_js(value: &JsValue) -> Option<Self> {
+        match get_string(value, "kind")?.as_str() {
+            "ready" => Some(Response::Ready),
+            "report" => {
+                let phases = Array::from(&get(value, "phases")?)
+                    .iter()
+                    .map(|phase| phase_from_js(&phase))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(Response::Report(RunReport {
+                    day: get_day(value)?,
+                    phases,
+                }))
+            }
+            "error" => Some(Response::Error {
+                day: get_day(value)?,
+                message: get_string(value, "message").unwrap_or_default(),
+            }),
+            "panic" => Some(Response::Panic {
+                day: get_day(value)?,
+                panic: panic_from_js(value),
+            }),
+            "grid" => Some(Response::Grid {
+                day: get_day(value)?,
+                grid: get(value, "grid").and_then(|grid| grid_from_js(&grid)),
+            }),
+            "frames" => Some(Response::Frames {
+                day: get_day(value)?,
+                frames: Array::from(&get(value, "frames")?)
+                    .iter()
+                    .map(|frame| frame_from_js(&frame))
+                    .collect::<Option<Vec<_>>>()?,
+            }),
+            _ => None,
+        }
+    }
+}
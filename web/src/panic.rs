@@ -0,0 +1,33 @@
+use crate::ui::UIRef;
+use solutions::runner::Panic;
+use std::cell::RefCell;
+use std::panic;
+
+thread_local! {
+    static UI: RefCell<Option<UIRef>> = const { RefCell::new(None) };
+}
+
+// A panic aborts the running wasm call, but the instance and the listeners stay alive, so
+// resetting the outputs of the day is enough to keep the page usable.
+pub fn install(ui_ref: UIRef) {
+    UI.with(|ui| *ui.borrow_mut() = Some(ui_ref));
+
+    panic::set_hook(Box::new(|info| {
+        let panic = Panic::from_payload(info.payload(), info.location());
+
+        gloo_console::error!(panic.to_string());
+
+        UI.with(|ui| {
+            let Ok(mut ui) = ui.try_borrow_mut() else {
+                return;
+            };
+
+            if let Some(ui_ref) = ui.as_mut() {
+                let day = ui_ref.day();
+
+                ui_ref.clear_outputs();
+                ui_ref.fail_status(day, "panicked", panic);
+            }
+        });
+    }));
+}
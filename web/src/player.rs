@@ -0,0 +1,152 @@
+use crate::canvas::GridCanvas;
+use gloo_timers::callback::Interval;
+use solutions::visualize::Frame;
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::{HtmlButtonElement, HtmlDivElement, HtmlPreElement, HtmlSpanElement};
+
+const FRAME_MS: u32 = 100;
+
+#[derive(Default)]
+struct State {
+    frames: Vec<Frame>,
+    index: usize,
+    interval: Option<Interval>,
+}
+
+#[derive(Clone)]
+pub struct Player {
+    grid: GridCanvas,
+    controls: HtmlDivElement,
+    play: HtmlButtonElement,
+    counter: HtmlSpanElement,
+    text: HtmlPreElement,
+    state: Rc<RefCell<State>>,
+}
+
+impl Player {
+    pub fn new(
+        grid: GridCanvas,
+        controls: HtmlDivElement,
+        play: HtmlButtonElement,
+        counter: HtmlSpanElement,
+        text: HtmlPreElement,
+    ) -> Self {
+        Self {
+            grid,
+            controls,
+            play,
+            counter,
+            text,
+            state: Rc::new(RefCell::new(State::default())),
+        }
+    }
+
+    // Opens on the last frame, which shows the finished run.
+    pub fn load(&self, frames: Vec<Frame>) {
+        self.pause();
+
+        {
+            let mut state = self.state.borrow_mut();
+            state.index = frames.len().saturating_sub(1);
+            state.frames = frames;
+        }
+
+        self.controls.set_class_name("row-flex");
+        self.show();
+    }
+
+    pub fn clear(&self) {
+        self.pause();
+        self.state.borrow_mut().frames.clear();
+
+        self.controls.set_class_name("row-flex hidden");
+        self.text.set_class_name("hidden");
+        self.grid.canvas().set_class_name("");
+    }
+
+    pub fn toggle(&self) {
+        if self.state.borrow().interval.is_some() {
+            self.pause();
+        } else {
+            self.start();
+        }
+    }
+
+    fn start(&self) {
+        {
+            let mut state = self.state.borrow_mut();
+
+            if state.frames.is_empty() {
+                return;
+            }
+
+            if state.index + 1 == state.frames.len() {
+                state.index = 0;
+            }
+        }
+
+        self.show();
+
+        let player = self.clone();
+        let interval = Interval::new(FRAME_MS, move || {
+            if !player.step(1) {
+                player.pause();
+            }
+        });
+
+        self.state.borrow_mut().interval = Some(interval);
+        self.play.set_inner_text("pause");
+    }
+
+    pub fn pause(&self) {
+        let interval = self.state.borrow_mut().interval.take();
+
+        drop(interval);
+        self.play.set_inner_text("play");
+    }
+
+    // Returns false when already at the first or last frame.
+    pub fn step(&self, delta: isize) -> bool {
+        {
+            let mut state = self.state.borrow_mut();
+
+            let last = state.frames.len().saturating_sub(1);
+            let index = state.index.saturating_add_signed(delta).min(last);
+
+            if index == state.index {
+                return false;
+            }
+
+            state.index = index;
+        }
+
+        self.show();
+
+        true
+    }
+
+    fn show(&self) {
+        let state = self.state.borrow();
+
+        let Some(frame) = state.frames.get(state.index) else {
+            return;
+        };
+
+        self.counter
+            .set_inner_text(&format!("frame {}/{}", state.index + 1, state.frames.len()));
+
+        match frame {
+            Frame::Grid(grid) => {
+                self.text.set_class_name("hidden");
+                self.grid.canvas().set_class_name("");
+                self.grid.replace(grid.clone());
+            }
+            Frame::Text(text) => {
+                self.grid.canvas().set_class_name("hidden");
+                self.text.set_class_name("");
+                self.text.set_inner_text(text);
+            }
+        }
+    }
+}
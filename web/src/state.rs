@@ -1,14 +1,81 @@
 use anyhow::{bail, Result};
-use web_sys::Location;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use solutions::solvers::Solvers;
+use solutions::Parts;
+use web_sys::{Location, UrlSearchParams};
+
+const VERSION: &str = "1";
+
+// Compressed custom inputs above this are left out of the link.
+pub const MAX_SHARED_INPUT: usize = 32 * 1024;
+
+const MAX_INFLATED_INPUT: usize = 4 * 1024 * 1024;
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub enum Source {
+    #[default]
+    Input,
+    Example(String),
+    Custom,
+}
+
+impl Source {
+    pub fn value(&self) -> String {
+        match self {
+            Source::Input => "input".to_string(),
+            Source::Example(name) => format!("example:{}", name),
+            Source::Custom => "custom".to_string(),
+        }
+    }
+
+    pub fn from_value(value: &str) -> Option<Source> {
+        match value {
+            "input" => Some(Source::Input),
+            "custom" => Some(Source::Custom),
+            _ => value
+                .strip_prefix("example:")
+                .map(|name| Source::Example(name.to_string())),
+        }
+    }
+}
+
+pub fn parts_value(parts: Parts) -> &'static str {
+    match parts {
+        Parts::One => "1",
+        Parts::Two => "2",
+        Parts::Both => "both",
+    }
+}
+
+pub fn compress(input: &str) -> String {
+    URL_SAFE_NO_PAD.encode(miniz_oxide::deflate::compress_to_vec(input.as_bytes(), 9))
+}
+
+pub fn decompress(encoded: &str) -> Option<String> {
+    let compressed = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+    let inflated =
+        miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, MAX_INFLATED_INPUT).ok()?;
+
+    String::from_utf8(inflated).ok()
+}
 
 #[derive(Clone)]
 pub struct State {
     pub day: u8,
+    pub source: Source,
+    pub parts: Parts,
+    pub input: Option<String>,
 }
 
 impl Default for State {
     fn default() -> Self {
-        Self { day: 11 }
+        Self {
+            day: Solvers::days().last().copied().unwrap_or(1) as u8,
+            source: Source::Input,
+            parts: Parts::Both,
+            input: None,
+        }
     }
 }
 
@@ -17,26 +84,114 @@ impl State {
         let href: String = location.href().ok()?;
         let split = href.split_once('#')?;
         let state_str = split.1;
-        let day = state_str.parse::<u8>().ok()?;
 
-        Some(State { day })
+        if state_str.contains('=') {
+            return State::from_params(state_str);
+        }
+
+        // Links from before the versioned encoding: `#11` or `#11/<example>`.
+        let (day_str, source) = match state_str.split_once('/') {
+            Some((day_str, "custom")) => (day_str, Source::Custom),
+            Some((day_str, example)) => (day_str, Source::Example(example.to_string())),
+            None => (state_str, Source::Input),
+        };
+
+        let day = day_str.parse::<u8>().ok()?;
+
+        Some(State {
+            day,
+            source,
+            ..State::default()
+        })
     }
 
-    pub fn write_location(&self, location: &mut Location) -> Result<()> {
-        let Ok(mut href) = location.href() else {
-            bail!("unable to get href")
+    fn from_params(state_str: &str) -> Option<State> {
+        let params = UrlSearchParams::new_with_str(state_str).ok()?;
+
+        if params.get("v")? != VERSION {
+            return None;
+        }
+
+        let day = params.get("day")?.parse::<u8>().ok()?;
+
+        let source = match params.get("source").as_deref() {
+            None | Some("input") => Source::Input,
+            Some("example") => Source::Example(params.get("example")?),
+            Some("custom") => Source::Custom,
+            Some(_) => return None,
+        };
+
+        let parts = match params.get("parts") {
+            Some(parts) => parts.parse().ok()?,
+            None => Parts::Both,
         };
 
-        if let Some(split) = href.split_once('#') {
-            href = format!("{}#{}", split.0, self.day);
-        } else {
-            href = format!("{}#{}", href, self.day);
+        let input = params.get("input").and_then(|encoded| decompress(&encoded));
+
+        Some(State {
+            day,
+            source,
+            parts,
+            input,
+        })
+    }
+
+    fn hash(&self) -> String {
+        let Ok(params) = UrlSearchParams::new() else {
+            return self.day.to_string();
+        };
+
+        params.append("v", VERSION);
+        params.append("day", &self.day.to_string());
+
+        match &self.source {
+            Source::Input => {}
+            Source::Example(name) => {
+                params.append("source", "example");
+                params.append("example", name);
+            }
+            Source::Custom => params.append("source", "custom"),
+        }
+
+        if self.parts != Parts::Both {
+            params.append("parts", parts_value(self.parts));
+        }
+
+        if let Some(input) = &self.input {
+            let encoded = compress(input);
+
+            if encoded.len() <= MAX_SHARED_INPUT {
+                params.append("input", &encoded);
+            }
         }
 
-        if location.set_href(&href).is_err() {
+        String::from(params.to_string())
+    }
+
+    fn href(&self, location: &Location) -> Result<String> {
+        let Ok(href) = location.href() else {
+            bail!("unable to get href")
+        };
+
+        let base = href.split_once('#').map(|split| split.0).unwrap_or(&href);
+
+        Ok(format!("{}#{}", base, self.hash()))
+    }
+
+    pub fn write_location(&self, location: &mut Location) -> Result<()> {
+        if location.set_href(&self.href(location)?).is_err() {
             bail!("unable to update href")
         }
 
         Ok(())
     }
+
+    // Updates the link without adding a history entry, used while typing.
+    pub fn replace_location(&self, location: &mut Location) -> Result<()> {
+        if location.replace(&self.href(location)?).is_err() {
+            bail!("unable to replace href")
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,43 @@
+use crate::state::Source;
+use anyhow::{anyhow, bail, Result};
+use web_sys::Storage;
+
+const PREFIX: &str = "aoc2023";
+
+// Browsers give an origin about 5 MB of local storage, often counted in UTF-16 code units.
+pub const MAX_SIZE: usize = 512 * 1024;
+
+fn storage() -> Option<Storage> {
+    gloo_utils::window().local_storage().ok().flatten()
+}
+
+fn key(day: usize, source: &Source) -> String {
+    format!("{}/input/{}/{}", PREFIX, day, source.value())
+}
+
+pub fn load(day: usize, source: &Source) -> Option<String> {
+    storage()?.get_item(&key(day, source)).ok().flatten()
+}
+
+pub fn save(day: usize, source: &Source, input: &str) -> Result<()> {
+    if input.len() > MAX_SIZE {
+        remove(day, source);
+        bail!(
+            "input is too large to save ({} KiB, limit {} KiB)",
+            input.len() / 1024,
+            MAX_SIZE / 1024
+        );
+    }
+
+    let storage = storage().ok_or_else(|| anyhow!("local storage is unavailable"))?;
+
+    storage
+        .set_item(&key(day, source), input)
+        .map_err(|_| anyhow!("local storage is full"))
+}
+
+pub fn remove(day: usize, source: &Source) {
+    if let Some(storage) = storage() {
+        let _result = storage.remove_item(&key(day, source));
+    }
+}
@@ -1,14 +1,50 @@
-use crate::state::State;
+use crate::canvas::GridCanvas;
+use crate::file;
+use crate::history::{self, History};
+use crate::player::Player;
+use crate::state::{parts_value, Source, State};
+use crate::storage;
 use anyhow::Result;
-use solutions::input;
+use gloo_timers::callback::Timeout;
+use solutions::grid::Grid;
+use solutions::provider::{Embedded, Provider};
+use solutions::runner::{Phase, RunReport};
+use solutions::solvers::Solvers;
+use solutions::visualize::Frame;
+use solutions::{example, input, Example, Parts};
+use std::cell::RefCell;
 use std::fmt::Display;
+use std::rc::Rc;
 use web_sys::wasm_bindgen::JsCast;
 use web_sys::{
-    EventTarget, HtmlButtonElement, HtmlDivElement, HtmlOptionElement, HtmlOptionsCollection,
-    HtmlSelectElement, HtmlTextAreaElement, Location,
+    EventTarget, File, HtmlButtonElement, HtmlCanvasElement, HtmlDivElement, HtmlInputElement,
+    HtmlOListElement, HtmlOptionElement, HtmlOptionsCollection, HtmlPreElement, HtmlSelectElement,
+    HtmlSpanElement, HtmlTextAreaElement, Location,
 };
 
-pub const MAX_DAY: usize = 11;
+const FLASH_MS: u32 = 2000;
+
+// Days built without an embedded input can still be solved on examples or pasted input.
+fn fill_days(day: &HtmlSelectElement) {
+    let inputs: Vec<usize> = input::days().collect();
+
+    for option_day in Solvers::days() {
+        let mut text = match Solvers::title(option_day) {
+            Some(title) => format!("{}: {}", option_day, title),
+            None => option_day.to_string(),
+        };
+
+        if !inputs.contains(&option_day) {
+            text.push_str(" (no input)");
+        }
+
+        if let Ok(option) =
+            HtmlOptionElement::new_with_text_and_value(&text, &option_day.to_string())
+        {
+            let _result = day.append_child(&option);
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct UIRef {
@@ -22,6 +58,31 @@ pub struct UIRef {
     part_2_button: HtmlButtonElement,
     previous: HtmlButtonElement,
     next: HtmlButtonElement,
+    cancel: HtmlButtonElement,
+    spinner: HtmlSpanElement,
+    part_1_time: HtmlSpanElement,
+    part_2_time: HtmlSpanElement,
+    history: HtmlOListElement,
+    runs: Rc<RefCell<History>>,
+    source: HtmlSelectElement,
+    part_1_check: HtmlSpanElement,
+    part_2_check: HtmlSpanElement,
+    reset: HtmlButtonElement,
+    parts: HtmlSelectElement,
+    live: HtmlInputElement,
+    help: HtmlDivElement,
+    help_button: HtmlButtonElement,
+    file: HtmlInputElement,
+    open: HtmlButtonElement,
+    file_name: HtmlSpanElement,
+    visualize: HtmlInputElement,
+    visualizer: HtmlDivElement,
+    grid_caption: HtmlSpanElement,
+    grid: GridCanvas,
+    player: Player,
+    play: HtmlButtonElement,
+    step_back: HtmlButtonElement,
+    step_forward: HtmlButtonElement,
 }
 
 impl UIRef {
@@ -86,7 +147,172 @@ impl UIRef {
             .dyn_into::<HtmlButtonElement>()
             .unwrap();
 
-        day.set_selected_index((state.day - 1) as i32);
+        let cancel = gloo_utils::document()
+            .get_element_by_id("cancel")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let spinner = gloo_utils::document()
+            .get_element_by_id("spinner")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let part_1_time = gloo_utils::document()
+            .get_element_by_id("part-1-time")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let part_2_time = gloo_utils::document()
+            .get_element_by_id("part-2-time")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let history = gloo_utils::document()
+            .get_element_by_id("history")
+            .unwrap()
+            .dyn_into::<HtmlOListElement>()
+            .unwrap();
+
+        let source = gloo_utils::document()
+            .get_element_by_id("source")
+            .unwrap()
+            .dyn_into::<HtmlSelectElement>()
+            .unwrap();
+
+        let part_1_check = gloo_utils::document()
+            .get_element_by_id("part-1-check")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let part_2_check = gloo_utils::document()
+            .get_element_by_id("part-2-check")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let reset = gloo_utils::document()
+            .get_element_by_id("reset")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let parts = gloo_utils::document()
+            .get_element_by_id("parts")
+            .unwrap()
+            .dyn_into::<HtmlSelectElement>()
+            .unwrap();
+
+        parts.set_value(parts_value(state.parts));
+        let live = gloo_utils::document()
+            .get_element_by_id("live")
+            .unwrap()
+            .dyn_into::<HtmlInputElement>()
+            .unwrap();
+
+        let help = gloo_utils::document()
+            .get_element_by_id("help")
+            .unwrap()
+            .dyn_into::<HtmlDivElement>()
+            .unwrap();
+
+        let help_button = gloo_utils::document()
+            .get_element_by_id("help-button")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let file = gloo_utils::document()
+            .get_element_by_id("file")
+            .unwrap()
+            .dyn_into::<HtmlInputElement>()
+            .unwrap();
+
+        let open = gloo_utils::document()
+            .get_element_by_id("open")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let file_name = gloo_utils::document()
+            .get_element_by_id("file-name")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let visualize = gloo_utils::document()
+            .get_element_by_id("visualize")
+            .unwrap()
+            .dyn_into::<HtmlInputElement>()
+            .unwrap();
+
+        let visualizer = gloo_utils::document()
+            .get_element_by_id("visualizer-container")
+            .unwrap()
+            .dyn_into::<HtmlDivElement>()
+            .unwrap();
+
+        let grid_caption = gloo_utils::document()
+            .get_element_by_id("grid-caption")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let grid = gloo_utils::document()
+            .get_element_by_id("grid")
+            .unwrap()
+            .dyn_into::<HtmlCanvasElement>()
+            .unwrap();
+
+        let player = gloo_utils::document()
+            .get_element_by_id("player")
+            .unwrap()
+            .dyn_into::<HtmlDivElement>()
+            .unwrap();
+
+        let play = gloo_utils::document()
+            .get_element_by_id("play")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let step_back = gloo_utils::document()
+            .get_element_by_id("step-back")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let step_forward = gloo_utils::document()
+            .get_element_by_id("step-forward")
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+
+        let frame = gloo_utils::document()
+            .get_element_by_id("frame")
+            .unwrap()
+            .dyn_into::<HtmlSpanElement>()
+            .unwrap();
+
+        let frame_text = gloo_utils::document()
+            .get_element_by_id("frame-text")
+            .unwrap()
+            .dyn_into::<HtmlPreElement>()
+            .unwrap();
+
+        let grid = GridCanvas::new(grid);
+        let player = Player::new(grid.clone(), player, play.clone(), frame, frame_text);
+
+        fill_days(&day);
+        day.set_value(&state.day.to_string());
+
+        if day.selected_index() < 0 {
+            day.set_value(&State::default().day.to_string());
+        }
 
         Self {
             input,
@@ -99,12 +325,47 @@ impl UIRef {
             part_2_button,
             previous,
             next,
+            cancel,
+            spinner,
+            part_1_time,
+            part_2_time,
+            history,
+            runs: Rc::new(RefCell::new(History::default())),
+            source,
+            part_1_check,
+            part_2_check,
+            reset,
+            parts,
+            live,
+            help,
+            help_button,
+            file,
+            open,
+            file_name,
+            visualize,
+            visualizer,
+            grid_caption,
+            grid,
+            player,
+            play,
+            step_back,
+            step_forward,
         }
     }
 
     fn state(&self) -> State {
+        let source = self.source();
+
+        let input = match source {
+            Source::Custom => Some(self.input()).filter(|input| !input.is_empty()),
+            _ => None,
+        };
+
         State {
             day: self.day() as u8,
+            source,
+            parts: self.selected_parts(),
+            input,
         }
     }
 
@@ -128,10 +389,169 @@ impl UIRef {
         self.next.dyn_ref::<EventTarget>().unwrap()
     }
 
+    pub fn parts_event_target(&self) -> &EventTarget {
+        self.parts.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn source_event_target(&self) -> &EventTarget {
+        self.source.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn input_event_target(&self) -> &EventTarget {
+        self.input.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn reset_event_target(&self) -> &EventTarget {
+        self.reset.dyn_ref::<EventTarget>().unwrap()
+    }
+
     pub fn previous_event_target(&self) -> &EventTarget {
         self.previous.dyn_ref::<EventTarget>().unwrap()
     }
 
+    pub fn cancel_event_target(&self) -> &EventTarget {
+        self.cancel.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn live_event_target(&self) -> &EventTarget {
+        self.live.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn help_event_target(&self) -> &EventTarget {
+        self.help.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn help_button_event_target(&self) -> &EventTarget {
+        self.help_button.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn file_event_target(&self) -> &EventTarget {
+        self.file.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn open_event_target(&self) -> &EventTarget {
+        self.open.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn open_file_picker(&self) {
+        self.file.click();
+    }
+
+    pub fn picked_file(&mut self) -> Option<File> {
+        let file = self.file.files()?.get(0);
+
+        // Cleared so picking the same file again still fires a change.
+        self.file.set_value("");
+
+        file
+    }
+
+    pub fn set_dragging(&mut self, dragging: bool) {
+        self.input
+            .set_class_name(if dragging { "dragging" } else { "" });
+    }
+
+    pub fn load_file(&mut self, name: &str, size: f64, result: Result<String>) {
+        let day = self.day();
+
+        let input = match result {
+            Ok(input) => input,
+            Err(error) => {
+                self.fail_status(day, &format!("rejected {}", name), error);
+                return;
+            }
+        };
+
+        self.select_source(&Source::Custom);
+        self.set_input(&input);
+        self.handle_input_edit();
+        self.replace_custom_location();
+
+        let description = format!("{} ({})", name, file::format_size(size));
+
+        self.file_name.set_inner_text(&description);
+        self.set_status(&format!("Day {} loaded {}", day, description));
+    }
+
+    pub fn visualize_event_target(&self) -> &EventTarget {
+        self.visualize.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn grid_event_target(&self) -> &EventTarget {
+        self.grid.canvas().dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn grid(&self) -> &GridCanvas {
+        &self.grid
+    }
+
+    pub fn play_event_target(&self) -> &EventTarget {
+        self.play.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn step_back_event_target(&self) -> &EventTarget {
+        self.step_back.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn step_forward_event_target(&self) -> &EventTarget {
+        self.step_forward.dyn_ref::<EventTarget>().unwrap()
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn visualize(&self) -> bool {
+        self.visualize.checked()
+    }
+
+    pub fn set_visualizing(&mut self, visualizing: bool) {
+        self.visualizer
+            .set_class_name(if visualizing { "" } else { "hidden" });
+    }
+
+    pub fn clear_grid(&mut self) {
+        self.player.clear();
+        self.grid.show(None);
+        self.grid_caption
+            .set_inner_text(&format!("Solve day {} to draw its grid", self.day()));
+    }
+
+    pub fn show_grid(&mut self, day: usize, grid: Option<Grid>) {
+        let caption = match grid {
+            Some(_) => "drag to pan, scroll to zoom, double-click to fit".to_string(),
+            None => format!("Day {} has no grid to draw", day),
+        };
+
+        self.player.clear();
+        self.grid.show(grid);
+        self.grid_caption.set_inner_text(&caption);
+    }
+
+    pub fn show_frames(&mut self, frames: Vec<Frame>) {
+        if frames.is_empty() {
+            return;
+        }
+
+        self.grid_caption.set_inner_text(&format!(
+            "{} frames, play or step through the run",
+            frames.len()
+        ));
+        self.player.load(frames);
+    }
+
+    pub fn live(&self) -> bool {
+        self.live.checked()
+    }
+
+    pub fn help_open(&self) -> bool {
+        self.help.class_name() != "hidden"
+    }
+
+    pub fn show_help(&mut self, open: bool) {
+        self.help.set_class_name(if open { "" } else { "hidden" });
+    }
+
     pub fn day(&self) -> usize {
         let options: HtmlOptionsCollection = self.day.options();
         let index = self.day.selected_index();
@@ -168,6 +588,7 @@ impl UIRef {
 
     pub fn set_status(&mut self, status: &str) {
         self.status.set_inner_text(status);
+        self.status.set_class_name("");
     }
 
     pub fn clear_outputs(&mut self) {
@@ -175,10 +596,187 @@ impl UIRef {
         self.set_part_2("");
         self.part_1_button.set_disabled(true);
         self.part_2_button.set_disabled(true);
+        self.part_1_time.set_inner_text("");
+        self.part_2_time.set_inner_text("");
+
+        for check in [&self.part_1_check, &self.part_2_check] {
+            check.set_inner_text("");
+            check.set_class_name("check");
+        }
+    }
+
+    pub fn source(&self) -> Source {
+        Source::from_value(&self.source.value()).unwrap_or_default()
     }
 
-    pub fn fail_status(&mut self, day: usize, expect: &str, error: impl Display) {
-        self.set_status(&format!("day {} expect {} error={}", day, expect, error))
+    fn example(&self) -> Option<&'static Example> {
+        match self.source() {
+            Source::Example(name) => example::get(self.day(), &name),
+            _ => None,
+        }
+    }
+
+    fn selected_parts(&self) -> Parts {
+        self.parts.value().parse().unwrap_or(Parts::Both)
+    }
+
+    // The selected parts narrowed to the parts the loaded example applies to, if any.
+    pub fn parts(&self) -> Option<Parts> {
+        let selected = self.selected_parts();
+
+        match self.example() {
+            Some(example) => selected.intersect(example.parts),
+            None => Some(selected),
+        }
+    }
+
+    pub fn handle_parts_change(&mut self) {
+        self.clear_outputs();
+
+        let _result = self.save_state(&mut gloo_utils::window().location());
+    }
+
+    fn fill_sources(&mut self) {
+        self.source.set_inner_text("");
+
+        let examples = example::all(self.day())
+            .iter()
+            .map(|example| Source::Example(example.name.to_string()));
+
+        for source in std::iter::once(Source::Input)
+            .chain(examples)
+            .chain(std::iter::once(Source::Custom))
+        {
+            let text = match &source {
+                Source::Input => "real".to_string(),
+                Source::Example(name) => format!("example {}", name),
+                Source::Custom => "custom".to_string(),
+            };
+
+            if let Ok(option) = HtmlOptionElement::new_with_text_and_value(&text, &source.value()) {
+                let _result = self.source.append_child(&option);
+            }
+        }
+    }
+
+    fn check(&self, part: u8, solution: &str) {
+        let check = match part {
+            1 => &self.part_1_check,
+            _ => &self.part_2_check,
+        };
+
+        // An edited example no longer has the example's answers.
+        if storage::load(self.day(), &self.source()).is_some() {
+            return;
+        }
+
+        match self.example().and_then(|example| example.expected(part)) {
+            Some(expected) if expected == solution => {
+                check.set_inner_text("pass");
+                check.set_class_name("check pass");
+            }
+            Some(expected) => {
+                check.set_inner_text(&format!("fail, expected {}", expected));
+                check.set_class_name("check fail");
+            }
+            None => {}
+        }
+    }
+
+    // Shows a confirmation, then puts back the status it covered unless another replaced it.
+    pub fn flash_status(&mut self, status: &str) {
+        let previous = self.status.inner_text();
+        let previous_class = self.status.class_name();
+
+        self.set_status(status);
+
+        let element = self.status.clone();
+        let status = status.to_string();
+
+        Timeout::new(FLASH_MS, move || {
+            if element.inner_text() == status {
+                element.set_inner_text(&previous);
+                element.set_class_name(&previous_class);
+            }
+        })
+        .forget();
+    }
+
+    pub fn fail_status(&mut self, day: usize, failure: &str, error: impl Display) {
+        self.set_status(&format!("Day {} {}: {}", day, failure, error));
+        self.status.set_class_name("error");
+    }
+
+    pub fn set_running(&mut self, running: bool) {
+        self.solve.set_disabled(running);
+        self.cancel.set_disabled(!running);
+        self.spinner
+            .set_class_name(if running { "spinning" } else { "hidden" });
+    }
+
+    pub fn show_report(&mut self, report: &RunReport) {
+        let day = report.day;
+
+        if let Some(part_1) = report.answer(1) {
+            self.set_part_1(part_1);
+            self.check(1, part_1);
+        }
+
+        if let Some(part_2) = report.answer(2) {
+            self.set_part_2(part_2);
+            self.check(2, part_2);
+        }
+
+        for (phase, time) in [
+            (Phase::Part1, &self.part_1_time),
+            (Phase::Part2, &self.part_2_time),
+        ] {
+            let text = report
+                .duration(phase)
+                .map(history::format_duration)
+                .unwrap_or_default();
+
+            time.set_inner_text(&text);
+        }
+
+        match report.failure() {
+            Some(failure) => self.fail_status(
+                day,
+                &format!("{} {}", failure.phase.name(), failure.outcome.status()),
+                failure.error().unwrap_or_default(),
+            ),
+            None => self.set_status(&format!(
+                "Day {} solved in {}",
+                day,
+                history::summary(report)
+            )),
+        }
+
+        self.runs.borrow_mut().push(report.clone());
+        self.show_history();
+    }
+
+    fn show_history(&mut self) {
+        self.history.set_inner_text("");
+
+        for report in self.runs.borrow().runs(self.day()) {
+            let Ok(item) = gloo_utils::document().create_element("li") else {
+                continue;
+            };
+
+            let text = match report.failure() {
+                Some(failure) => format!(
+                    "{} - {} {}",
+                    history::summary(report),
+                    failure.phase.name(),
+                    failure.outcome.status()
+                ),
+                None => history::summary(report),
+            };
+
+            item.set_text_content(Some(&text));
+            let _result = self.history.append_child(&item);
+        }
     }
 
     pub fn part_1(&self) -> String {
@@ -190,38 +788,123 @@ impl UIRef {
     }
 
     pub fn handle_day_change(&mut self) {
+        self.clear_grid();
+        self.fill_sources();
+        self.source.set_value(&Source::Input.value());
+        self.handle_source_change();
+        self.show_history();
+
+        let index = self.day.selected_index();
+
+        self.next
+            .set_disabled(index + 1 >= self.day.options().length() as i32);
+        self.previous.set_disabled(index <= 0);
+    }
+
+    fn original_input(&self) -> Result<String> {
+        match self.source() {
+            Source::Input => Embedded.input(self.day()),
+            Source::Example(_) => Ok(self
+                .example()
+                .map(|example| example.input.to_string())
+                .unwrap_or_default()),
+            Source::Custom => Ok(String::new()),
+        }
+    }
+
+    fn load_original(&mut self) {
+        match self.original_input() {
+            Ok(input) => self.set_input(&input),
+            Err(error) => {
+                self.set_input("");
+                self.set_status(&error.to_string());
+            }
+        }
+    }
+
+    pub fn handle_source_change(&mut self) {
         self.clear_outputs();
-        self.set_input(input::get(self.day()));
+        self.file_name.set_inner_text("");
+
+        let day = self.day();
+        let source = self.source();
+
+        match storage::load(day, &source) {
+            Some(input) => {
+                self.set_input(&input);
+                self.set_status(&format!("Day {} restored edited input", day));
+            }
+            None => self.load_original(),
+        }
+
+        self.reset
+            .set_disabled(storage::load(day, &source).is_none());
+
         let _result = self.save_state(&mut gloo_utils::window().location());
+    }
 
-        if self.day() == MAX_DAY {
-            self.next.set_disabled(true);
-        } else {
-            self.next.set_disabled(false);
+    pub fn handle_input_edit(&mut self) {
+        let day = self.day();
+        let source = self.source();
+        let input = self.input();
+
+        if self
+            .original_input()
+            .is_ok_and(|original| original == input)
+        {
+            storage::remove(day, &source);
+        } else if let Err(error) = storage::save(day, &source, &input) {
+            self.fail_status(day, "input not saved", error);
         }
 
-        if self.day() == 1 {
-            self.previous.set_disabled(true);
-        } else {
-            self.previous.set_disabled(false);
+        self.reset
+            .set_disabled(storage::load(day, &source).is_none());
+    }
+
+    // A custom input is part of the link, so the address bar follows its edits.
+    pub fn replace_custom_location(&self) {
+        if self.source() == Source::Custom {
+            let _result = self
+                .state()
+                .replace_location(&mut gloo_utils::window().location());
         }
     }
 
+    pub fn reset_input(&mut self) {
+        storage::remove(self.day(), &self.source());
+
+        self.clear_outputs();
+        self.load_original();
+        self.reset.set_disabled(true);
+    }
+
+    pub fn select_source(&mut self, source: &Source) -> bool {
+        self.source.set_value(&source.value());
+
+        if self.source() != *source {
+            return false;
+        }
+
+        self.handle_source_change();
+
+        true
+    }
+
     pub fn next_day(&mut self) {
-        let day = self.day();
+        let index = self.day.selected_index();
 
-        if day < MAX_DAY {
-            self.day.set_selected_index(day as i32)
+        if index + 1 < self.day.options().length() as i32 {
+            self.day.set_selected_index(index + 1)
         }
 
         self.handle_day_change();
     }
 
     pub fn previous_day(&mut self) {
-        let day = self.day();
+        let index = self.day.selected_index();
 
-        if day > 1 {
-            self.day.set_selected_index(day as i32 - 2)
+        if index > 0 {
+            self.day.set_selected_index(index - 1)
         }
 
         self.handle_day_change();
@@ -0,0 +1,282 @@
+use crate::clock::PerformanceClock;
+use crate::message::{Request, Response};
+use crate::ui::UIRef;
+use solutions::runner::{Panic, Runner};
+use solutions::visualize::Visualize;
+use solutions::Solver;
+use std::cell::{Cell, RefCell};
+use std::panic;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{DedicatedWorkerGlobalScope, ErrorEvent, EventTarget, MessageEvent, Worker};
+
+// Loader generated by trunk for the worker binary, see app/index.html.
+const WORKER_URL: &str = "./worker_loader.js";
+
+thread_local! {
+    static DAY: Cell<usize> = const { Cell::new(0) };
+}
+
+fn worker_scope() -> DedicatedWorkerGlobalScope {
+    js_sys::global().unchecked_into()
+}
+
+fn post(response: Response) {
+    let _result = worker_scope().post_message(&response.to_js());
+}
+
+fn handle_request(request: Request) {
+    DAY.with(|day| day.set(request.day));
+
+    let runner = Runner::new(PerformanceClock::default());
+
+    // Sent ahead of the report, so the page still treats the run as current.
+    let inspect = |solver: &dyn Solver| {
+        if !request.grid {
+            return;
+        }
+
+        post(Response::Grid {
+            day: request.day,
+            grid: solver.grid(),
+        });
+
+        if let Some(Ok(frames)) = solver.visualize().map(Visualize::frames) {
+            post(Response::Frames {
+                day: request.day,
+                frames,
+            });
+        }
+    };
+
+    match runner.run_with(request.day, &request.input, request.parts, inspect) {
+        Ok(report) => post(Response::Report(report)),
+        Err(err) => post(Response::Error {
+            day: request.day,
+            message: err.to_string(),
+        }),
+    }
+}
+
+// Entry point of the worker binary. A panic aborts the wasm call, so the hook reports it to the
+// page before that happens and the page replaces the worker.
+pub fn serve() {
+    panic::set_hook(Box::new(|info| {
+        post(Response::Panic {
+            day: DAY.with(Cell::get),
+            panic: Panic::from_payload(info.payload(), info.location()),
+        })
+    }));
+
+    let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(|event: MessageEvent| {
+        if let Some(request) = Request::from_js(&event.data()) {
+            handle_request(request);
+        }
+    });
+
+    worker_scope().set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
+
+    post(Response::Ready);
+}
+
+struct Handlers {
+    message: Closure<dyn FnMut(MessageEvent)>,
+    error: Closure<dyn FnMut(ErrorEvent)>,
+}
+
+#[derive(Default)]
+struct Inner {
+    worker: Option<Worker>,
+    handlers: Option<Handlers>,
+    ready: bool,
+    pending: Option<Request>,
+    running: Option<usize>,
+}
+
+#[derive(Clone)]
+pub struct SolverWorker {
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl SolverWorker {
+    pub fn new(ui_ref: UIRef) -> Self {
+        let solver_worker = Self {
+            inner: Rc::new(RefCell::new(Inner::default())),
+        };
+
+        let worker = solver_worker.clone();
+        let mut ui_clone = ui_ref.clone();
+
+        let message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            if !worker.is_current(event.target()) {
+                return;
+            }
+
+            if let Some(response) = Response::from_js(&event.data()) {
+                worker.handle_response(&mut ui_clone, response);
+            }
+        });
+
+        let worker = solver_worker.clone();
+        let mut ui_clone = ui_ref;
+
+        let error = Closure::<dyn FnMut(ErrorEvent)>::new(move |event: ErrorEvent| {
+            event.prevent_default();
+
+            if worker.is_current(event.target()) {
+                worker.handle_error(&mut ui_clone, &event.message());
+            }
+        });
+
+        solver_worker.inner.borrow_mut().handlers = Some(Handlers { message, error });
+        solver_worker.spawn();
+
+        solver_worker
+    }
+
+    fn spawn(&self) {
+        let mut inner = self.inner.borrow_mut();
+
+        if let Some(worker) = inner.worker.take() {
+            worker.terminate();
+        }
+
+        inner.ready = false;
+
+        let worker = match Worker::new(WORKER_URL) {
+            Ok(worker) => worker,
+            Err(err) => {
+                gloo_console::error!(err);
+                return;
+            }
+        };
+
+        if let Some(handlers) = &inner.handlers {
+            worker.set_onmessage(Some(handlers.message.as_ref().unchecked_ref()));
+            worker.set_onerror(Some(handlers.error.as_ref().unchecked_ref()));
+        }
+
+        inner.worker = Some(worker);
+    }
+
+    fn post(inner: &Inner, request: &Request) {
+        if let Some(worker) = &inner.worker {
+            let _result = worker.post_message(&request.to_js());
+        }
+    }
+
+    pub fn solve(&self, request: Request) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.running = Some(request.day);
+
+        if inner.ready {
+            Self::post(&inner, &request);
+        } else {
+            inner.pending = Some(request);
+        }
+    }
+
+    pub fn running(&self) -> Option<usize> {
+        self.inner.borrow().running
+    }
+
+    pub fn cancel(&self) -> Option<usize> {
+        let day = {
+            let mut inner = self.inner.borrow_mut();
+            inner.pending = None;
+            inner.running.take()
+        };
+
+        if day.is_some() {
+            self.spawn();
+        }
+
+        day
+    }
+
+    // Events of a terminated worker can still be delivered after it was replaced.
+    fn is_current(&self, target: Option<EventTarget>) -> bool {
+        let inner = self.inner.borrow();
+
+        match (&inner.worker, target) {
+            (Some(worker), Some(target)) => target.dyn_ref::<Worker>() == Some(worker),
+            _ => false,
+        }
+    }
+
+    fn finish(&self, day: usize) -> bool {
+        let mut inner = self.inner.borrow_mut();
+
+        if inner.running != Some(day) {
+            return false;
+        }
+
+        inner.running = None;
+
+        true
+    }
+
+    fn handle_response(&self, ui_ref: &mut UIRef, response: Response) {
+        match response {
+            Response::Ready => {
+                let mut inner = self.inner.borrow_mut();
+                inner.ready = true;
+
+                if let Some(request) = inner.pending.take() {
+                    Self::post(&inner, &request);
+                }
+            }
+            Response::Report(report) => {
+                if self.finish(report.day) {
+                    ui_ref.set_running(false);
+                    ui_ref.show_report(&report);
+                }
+            }
+            Response::Error { day, message } => {
+                if self.finish(day) {
+                    ui_ref.set_running(false);
+                    ui_ref.fail_status(day, "solve failed", message);
+                }
+            }
+            Response::Grid { day, grid } => {
+                if self.inner.borrow().running == Some(day) {
+                    ui_ref.show_grid(day, grid);
+                }
+            }
+            Response::Frames { day, frames } => {
+                if self.inner.borrow().running == Some(day) {
+                    ui_ref.show_frames(frames);
+                }
+            }
+            Response::Panic { day, panic } => {
+                if self.finish(day) {
+                    ui_ref.set_running(false);
+                    ui_ref.clear_outputs();
+                    ui_ref.fail_status(day, "panicked", panic);
+                }
+
+                self.spawn();
+            }
+        }
+    }
+
+    fn handle_error(&self, ui_ref: &mut UIRef, message: &str) {
+        let running = self.inner.borrow_mut().running.take();
+
+        let Some(day) = running else {
+            if !self.inner.borrow().ready {
+                ui_ref.set_status(&format!("solver worker failed to start: {}", message));
+            }
+            return;
+        };
+
+        ui_ref.set_running(false);
+        ui_ref.clear_outputs();
+        ui_ref.fail_status(day, "worker crashed", message);
+
+        self.spawn();
+    }
+}